@@ -0,0 +1,591 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, Lit, LitInt, LitStr, Token};
+
+use crate::option_inner;
+
+/// Discord allows at most 25 options in a command and 25 choices in an option.
+const MAX_OPTIONS: usize = 25;
+
+/// Whether the name is a valid command or option name. Mirrors the check done during conversion.
+fn is_valid_name(name: &str) -> bool {
+    let length = name.chars().count();
+    (1..=32).contains(&length)
+        && name
+            .chars()
+            .all(|c| (c.is_alphanumeric() || matches!(c, '-' | '_' | '\'')) && !c.is_uppercase())
+}
+
+fn check_name(name: &LitStr) -> syn::Result<()> {
+    if is_valid_name(&name.value()) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            name.span(),
+            "names must be 1 to 32 lowercase letters, digits, `-`, `_` or `'`",
+        ))
+    }
+}
+
+fn check_description(description: &LitStr) -> syn::Result<()> {
+    let length = description.value().encode_utf16().count();
+    if (1..=100).contains(&length) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            description.span(),
+            format!("descriptions must be between 1 and 100 characters, but this one is {length}"),
+        ))
+    }
+}
+
+/// `"BanMember"` -> `"ban-member"`
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
+/// A `min` or `max` value. Integer literals are kept as integers so that large limits are not rounded.
+#[derive(Clone, Copy)]
+enum Limit {
+    Integer(i64),
+    Number(f64),
+}
+
+impl Limit {
+    fn is_larger_than(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Integer(value), Self::Integer(other)) => value > other,
+            _ => self.as_f64() > other.as_f64(),
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Number(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => value.fmt(f),
+            Self::Number(value) => value.fmt(f),
+        }
+    }
+}
+
+impl quote::ToTokens for Limit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let limit = quote!(::serenity_builder::model::command::SerenityCommandOptionLimit);
+        tokens.extend(match *self {
+            Self::Integer(value) => {
+                let value = Literal::i64_suffixed(value);
+                quote!(#limit::Integer(#value))
+            }
+            Self::Number(value) => {
+                let value = Literal::f64_suffixed(value);
+                quote!(#limit::Number(#value))
+            }
+        });
+    }
+}
+
+/// Parse `= 7`, `= -7` or `= 0.5`.
+fn parse_number(meta: &ParseNestedMeta) -> syn::Result<Limit> {
+    let input = meta.value()?;
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    match input.parse::<Lit>()? {
+        Lit::Int(lit) => {
+            let value = lit.base10_parse::<i128>()?;
+            i64::try_from(if negative { -value } else { value })
+                .map(Limit::Integer)
+                .map_err(|_| syn::Error::new(lit.span(), "integer limits must fit in an i64"))
+        }
+        Lit::Float(lit) => {
+            let value = lit.base10_parse::<f64>()?;
+            Ok(Limit::Number(if negative { -value } else { value }))
+        }
+        lit => Err(syn::Error::new(lit.span(), "expected a number")),
+    }
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, meta: &ParseNestedMeta) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("this attribute is already set"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// The parsed `#[command(...)]` attributes of the struct or a field.
+#[derive(Default)]
+struct CommandAttrs {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    min: Option<Limit>,
+    max: Option<Limit>,
+    min_length: Option<u16>,
+    max_length: Option<u16>,
+}
+
+fn parse_command_attrs(attrs: &[Attribute], on_field: bool) -> syn::Result<CommandAttrs> {
+    let mut parsed = CommandAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("command")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let name = meta.value()?.parse::<LitStr>()?;
+                check_name(&name)?;
+                set_once(&mut parsed.name, name, &meta)
+            } else if meta.path.is_ident("description") {
+                let description = meta.value()?.parse::<LitStr>()?;
+                check_description(&description)?;
+                set_once(&mut parsed.description, description, &meta)
+            } else if on_field && meta.path.is_ident("min") {
+                let min = parse_number(&meta)?;
+                set_once(&mut parsed.min, min, &meta)
+            } else if on_field && meta.path.is_ident("max") {
+                let max = parse_number(&meta)?;
+                set_once(&mut parsed.max, max, &meta)
+            } else if on_field && meta.path.is_ident("min_length") {
+                let min_length = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                set_once(&mut parsed.min_length, min_length, &meta)
+            } else if on_field && meta.path.is_ident("max_length") {
+                let max_length = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                set_once(&mut parsed.max_length, max_length, &meta)
+            } else if on_field {
+                Err(meta.error(
+                    "unknown command attribute, expected one of: name, description, min, max, min_length, max_length",
+                ))
+            } else {
+                Err(meta.error("unknown command attribute, expected one of: name, description"))
+            }
+        })?;
+    }
+
+    if let (Some(min), Some(max)) = (parsed.min, parsed.max)
+        && min.is_larger_than(max)
+    {
+        return Err(syn::Error::new(
+            attrs[0].span(),
+            format!("`min` ({min}) must not be larger than `max` ({max})"),
+        ));
+    }
+    if let (Some(min), Some(max)) = (parsed.min_length, parsed.max_length)
+        && min > max
+    {
+        return Err(syn::Error::new(
+            attrs[0].span(),
+            format!("`min_length` ({min}) must not be larger than `max_length` ({max})"),
+        ));
+    }
+    for length in [parsed.min_length, parsed.max_length].into_iter().flatten() {
+        if length > 6000 {
+            return Err(syn::Error::new(
+                attrs[0].span(),
+                format!("string options can be at most 6000 characters long, but {length} is used"),
+            ));
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn optional_tokens<T: quote::ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+pub(crate) fn expand_command(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "SerenityCommand can only be derived for structs",
+        ));
+    };
+    let fields = match &data.fields {
+        Fields::Named(named) => named.named.iter().collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "SerenityCommand can only be derived for structs with named fields",
+            ));
+        }
+    };
+
+    let command_attrs = parse_command_attrs(&input.attrs, false)?;
+    let command_name = match command_attrs.name {
+        Some(name) => name,
+        None => {
+            let name = LitStr::new(&kebab_case(&input.ident.to_string()), input.ident.span());
+            check_name(&name)?;
+            name
+        }
+    };
+    let Some(command_description) = command_attrs.description else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "missing `#[command(description = \"...\")]` on the struct",
+        ));
+    };
+    if fields.len() > MAX_OPTIONS {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            format!(
+                "a command can have at most {MAX_OPTIONS} options, but {} are used",
+                fields.len()
+            ),
+        ));
+    }
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    let mut option_names: Vec<String> = Vec::new();
+    let mut optional_seen = false;
+    let mut definitions = Vec::new();
+    let mut parsers = Vec::new();
+
+    for field in fields {
+        let attrs = match parse_command_attrs(&field.attrs, true) {
+            Ok(attrs) => attrs,
+            Err(error) => {
+                push_error(error);
+                continue;
+            }
+        };
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let name = attrs
+            .name
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        if let Err(error) = check_name(&name) {
+            push_error(error);
+            continue;
+        }
+        if option_names.contains(&name.value()) {
+            push_error(syn::Error::new(
+                name.span(),
+                format!("duplicate option name {:?}", name.value()),
+            ));
+            continue;
+        }
+        option_names.push(name.value());
+
+        let Some(description) = attrs.description else {
+            push_error(syn::Error::new(
+                ident.span(),
+                "missing `#[command(description = \"...\")]` on the option",
+            ));
+            continue;
+        };
+
+        let (ty, required) = match option_inner(&field.ty) {
+            Some(inner) => (inner, false),
+            None => (&field.ty, true),
+        };
+        if required && optional_seen {
+            push_error(syn::Error::new(
+                ident.span(),
+                "required options must come before optional ones",
+            ));
+            continue;
+        }
+        optional_seen |= !required;
+
+        let value = quote!(<#ty as ::serenity_builder::command::SerenityCommandOptionValue>);
+        let min_value = optional_tokens(attrs.min);
+        let max_value = optional_tokens(attrs.max);
+        let min_length = optional_tokens(attrs.min_length.map(Literal::u16_suffixed));
+        let max_length = optional_tokens(attrs.max_length.map(Literal::u16_suffixed));
+        definitions.push(quote! {
+            ::serenity_builder::model::command::SerenityCommandOption {
+                kind: #value::KIND,
+                name: ::std::string::String::from(#name),
+                description: ::std::string::String::from(#description),
+                required: #required,
+                choices: #value::choices(),
+                min_value: #min_value,
+                max_value: #max_value,
+                min_length: #min_length,
+                max_length: #max_length,
+            }
+        });
+
+        let read = if required {
+            quote!(::serenity_builder::command::required_option)
+        } else {
+            quote!(::serenity_builder::command::optional_option)
+        };
+        parsers.push(quote! {
+            #ident: #read::<#ty>(&data.options, #name)?
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let options = if definitions.is_empty() {
+        quote!(::core::option::Option::None)
+    } else {
+        quote!(::core::option::Option::Some(::std::vec![#(#definitions),*]))
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serenity_builder::command::SerenityCommandDefinition for #name #ty_generics #where_clause {
+            fn command() -> ::serenity_builder::model::command::SerenityCommand {
+                ::serenity_builder::model::command::SerenityCommand {
+                    name: ::std::string::String::from(#command_name),
+                    description: ::std::string::String::from(#command_description),
+                    options: #options,
+                }
+            }
+
+            fn parse(
+                data: &::serenity_builder::__private::CommandData,
+            ) -> ::core::result::Result<Self, ::serenity_builder::command::SerenityCommandParseError> {
+                if data.name != #command_name {
+                    return ::core::result::Result::Err(
+                        ::serenity_builder::command::SerenityCommandParseError::UnexpectedCommand {
+                            expected: ::std::string::String::from(#command_name),
+                            actual: ::core::clone::Clone::clone(&data.name),
+                        },
+                    );
+                }
+
+                ::core::result::Result::Ok(Self {
+                    #(#parsers),*
+                })
+            }
+        }
+    })
+}
+
+pub(crate) fn expand_choice(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "SerenityCommandChoice can only be derived for enums",
+        ));
+    };
+    if data.variants.is_empty() || data.variants.len() > MAX_OPTIONS {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            format!(
+                "an option must have between 1 and {MAX_OPTIONS} choices, but {} are used",
+                data.variants.len()
+            ),
+        ));
+    }
+
+    let mut choices = Vec::new();
+    let mut arms = Vec::new();
+    let mut values: Vec<String> = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.span(),
+                "SerenityCommandChoice only supports unit variants",
+            ));
+        }
+
+        let mut name: Option<LitStr> = None;
+        let mut value: Option<LitStr> = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("choice"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let parsed = meta.value()?.parse::<LitStr>()?;
+                    let length = parsed.value().encode_utf16().count();
+                    if !(1..=100).contains(&length) {
+                        return Err(syn::Error::new(
+                            parsed.span(),
+                            format!("choice names must be between 1 and 100 characters, but this one is {length}"),
+                        ));
+                    }
+                    set_once(&mut name, parsed, &meta)
+                } else if meta.path.is_ident("value") {
+                    let parsed = meta.value()?.parse::<LitStr>()?;
+                    set_once(&mut value, parsed, &meta)
+                } else {
+                    Err(meta.error("unknown choice attribute, expected one of: name, value"))
+                }
+            })?;
+        }
+
+        let ident = &variant.ident;
+        let default = || LitStr::new(&ident.to_string(), ident.span());
+        let name = name.unwrap_or_else(default);
+        let value = value.unwrap_or_else(default);
+        if values.contains(&value.value()) {
+            return Err(syn::Error::new(
+                value.span(),
+                format!("duplicate choice value {:?}", value.value()),
+            ));
+        }
+        values.push(value.value());
+
+        choices.push(quote! {
+            ::serenity_builder::model::command::SerenityCommandChoice {
+                name: ::std::string::String::from(#name),
+                value: ::serenity_builder::model::command::SerenityCommandChoiceValue::String(
+                    ::std::string::String::from(#value),
+                ),
+            }
+        });
+        arms.push(quote!(#value => ::core::option::Option::Some(Self::#ident)));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serenity_builder::command::SerenityCommandOptionValue for #name #ty_generics #where_clause {
+            const KIND: ::serenity_builder::__private::CommandOptionType =
+                ::serenity_builder::__private::CommandOptionType::String;
+
+            fn choices() -> ::core::option::Option<::std::vec::Vec<::serenity_builder::model::command::SerenityCommandChoice>> {
+                ::core::option::Option::Some(::std::vec![#(#choices),*])
+            }
+
+            fn from_value(
+                value: &::serenity_builder::__private::CommandDataOptionValue,
+            ) -> ::core::option::Option<Self> {
+                match value.as_str()? {
+                    #(#arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn expand_error(input: DeriveInput) -> String {
+        expand_command(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_expand_command() {
+        let input: DeriveInput = parse_quote! {
+            #[command(description = "Ban a member")]
+            struct BanMember {
+                #[command(description = "The member to ban")]
+                member: UserId,
+                #[command(description = "How many days of messages to delete", min = 0, max = 7)]
+                days: Option<i64>,
+            }
+        };
+
+        let expanded = expand_command(&input).unwrap().to_string();
+        assert!(expanded.contains("\"ban-member\""));
+        assert!(expanded.contains("required_option :: < UserId >"));
+        assert!(expanded.contains("optional_option :: < i64 >"));
+        assert!(expanded.contains("Integer (7i64)"));
+    }
+
+    #[test]
+    fn test_expand_command_misuse() {
+        assert!(
+            expand_error(parse_quote! {
+                struct NoDescription {}
+            })
+            .contains("missing `#[command(description")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                #[command(name = "Ban", description = "Ban a member")]
+                struct Ban {}
+            })
+            .contains("names must be 1 to 32 lowercase")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                #[command(description = "Ban a member")]
+                struct Ban {
+                    #[command(description = "The reason")]
+                    reason: Option<String>,
+                    #[command(description = "The member to ban")]
+                    member: UserId,
+                }
+            })
+            .contains("required options must come before optional ones")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                #[command(description = "Ban a member")]
+                struct Ban {
+                    #[command(description = "Days", min = 7, max = 0)]
+                    days: i64,
+                }
+            })
+            .contains("must not be larger than `max`")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                #[command(description = "Ban a member")]
+                struct Ban {
+                    member: UserId,
+                }
+            })
+            .contains("on the option")
+        );
+    }
+
+    #[test]
+    fn test_expand_choice() {
+        let input: DeriveInput = parse_quote! {
+            enum Mode {
+                #[choice(name = "Soft ban", value = "soft")]
+                Soft,
+                Hard,
+            }
+        };
+
+        let expanded = expand_choice(&input).unwrap().to_string();
+        assert!(
+            expanded.contains("\"soft\" => :: core :: option :: Option :: Some (Self :: Soft)")
+        );
+        assert!(expanded.contains("\"Hard\""));
+
+        let input: DeriveInput = parse_quote! {
+            enum Mode {
+                Days(u8),
+            }
+        };
+        assert!(
+            expand_choice(&input)
+                .unwrap_err()
+                .to_string()
+                .contains("only supports unit variants")
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+use crate::option_inner;
+
+/// Discord allows at most 25 fields in an embed.
+const MAX_FIELDS: usize = 25;
+
+/// What a struct field is used for in the embed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Title,
+    Description,
+    Url,
+    Author,
+    Footer,
+    Image,
+    Thumbnail,
+    Color,
+    Timestamp,
+    Field,
+    Skip,
+}
+
+impl Role {
+    const KEYS: [(&'static str, Role); 11] = [
+        ("title", Role::Title),
+        ("description", Role::Description),
+        ("url", Role::Url),
+        ("author", Role::Author),
+        ("footer", Role::Footer),
+        ("image", Role::Image),
+        ("thumbnail", Role::Thumbnail),
+        ("color", Role::Color),
+        ("timestamp", Role::Timestamp),
+        ("field", Role::Field),
+        ("skip", Role::Skip),
+    ];
+
+    fn key(self) -> &'static str {
+        Self::KEYS
+            .iter()
+            .find(|(_, role)| *role == self)
+            .map(|(key, _)| *key)
+            .unwrap_or_default()
+    }
+}
+
+/// The parsed `#[embed(...)]` attributes of a struct field.
+struct FieldAttrs {
+    role: Role,
+    name: Option<LitStr>,
+    inline: bool,
+}
+
+fn parse_field_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let mut role: Option<Role> = None;
+    let mut name = None;
+    let mut inline = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("embed"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident("inline") {
+                inline = true;
+                return Ok(());
+            }
+
+            let Some((key, new_role)) = Role::KEYS.iter().find(|(key, _)| meta.path.is_ident(key))
+            else {
+                return Err(meta.error(format!(
+                    "unknown embed attribute, expected one of: {}, name, inline",
+                    Role::KEYS.map(|(key, _)| key).join(", ")
+                )));
+            };
+            if let Some(role) = role {
+                return Err(meta.error(format!("`{key}` cannot be combined with `{}`", role.key())));
+            }
+            role = Some(*new_role);
+            Ok(())
+        })?;
+    }
+
+    let role = role.unwrap_or(Role::Field);
+    if role != Role::Field && (name.is_some() || inline) {
+        return Err(syn::Error::new(
+            field.span(),
+            "`name` and `inline` can only be used with `field`",
+        ));
+    }
+
+    Ok(FieldAttrs { role, name, inline })
+}
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "IntoSerenityEmbed can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "IntoSerenityEmbed can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    // The value expression for every role that can be used once.
+    let mut singles: Vec<(Role, TokenStream)> = Vec::new();
+    let mut fields = Vec::new();
+
+    for field in &named.named {
+        let attrs = match parse_field_attrs(field) {
+            Ok(attrs) => attrs,
+            Err(error) => {
+                push_error(error);
+                continue;
+            }
+        };
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let optional = option_inner(&field.ty).is_some();
+
+        match attrs.role {
+            Role::Skip => {}
+            Role::Field => {
+                let name = attrs
+                    .name
+                    .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+                let inline = attrs.inline;
+                let push = |value: TokenStream| {
+                    quote! {
+                        fields.push(::serenity_builder::model::embed::SerenityEmbedField {
+                            name: ::std::string::String::from(#name),
+                            value: ::std::string::ToString::to_string(#value),
+                            inline: #inline,
+                        });
+                    }
+                };
+                fields.push(if optional {
+                    let push = push(quote!(field));
+                    quote! {
+                        if let ::core::option::Option::Some(field) = &value.#ident {
+                            #push
+                        }
+                    }
+                } else {
+                    push(quote!(&value.#ident))
+                });
+            }
+            role => {
+                if singles.iter().any(|(used, _)| *used == role) {
+                    push_error(syn::Error::new(
+                        ident.span(),
+                        format!("duplicate `#[embed({})]`", role.key()),
+                    ));
+                    continue;
+                }
+
+                let expression = match role {
+                    Role::Color => {
+                        let convert = quote!(
+                                ::core::convert::Into::<
+                                    ::serenity_builder::model::color::SerenityColor,
+                                >::into
+                            );
+                        if optional {
+                            quote!(::core::clone::Clone::clone(&value.#ident).map(#convert))
+                        } else {
+                            quote!(::core::option::Option::Some(#convert(::core::clone::Clone::clone(&value.#ident))))
+                        }
+                    }
+                    Role::Timestamp => {
+                        let convert = quote!(
+                            ::core::convert::Into::<::serenity_builder::__private::Timestamp>::into
+                        );
+                        if optional {
+                            quote!(::core::clone::Clone::clone(&value.#ident).map(#convert))
+                        } else {
+                            quote!(::core::option::Option::Some(#convert(::core::clone::Clone::clone(&value.#ident))))
+                        }
+                    }
+                    _ => {
+                        if optional {
+                            quote!(value.#ident.as_ref().map(::std::string::ToString::to_string))
+                        } else {
+                            quote!(::core::option::Option::Some(::std::string::ToString::to_string(&value.#ident)))
+                        }
+                    }
+                };
+                singles.push((role, expression));
+            }
+        }
+    }
+
+    if fields.len() > MAX_FIELDS {
+        push_error(syn::Error::new(
+            input.ident.span(),
+            format!(
+                "an embed can have at most {MAX_FIELDS} fields, but {} fields are used; mark the others with `#[embed(skip)]`",
+                fields.len()
+            ),
+        ));
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let single = |role: Role| {
+        singles
+            .iter()
+            .find(|(used, _)| *used == role)
+            .map(|(_, expression)| expression.clone())
+            .unwrap_or_else(|| quote!(::core::option::Option::None))
+    };
+    let title = single(Role::Title);
+    let description = single(Role::Description);
+    let url = single(Role::Url);
+    let author = single(Role::Author);
+    let footer = single(Role::Footer);
+    let image = single(Role::Image);
+    let thumbnail = single(Role::Thumbnail);
+    let color = single(Role::Color);
+    let timestamp = single(Role::Timestamp);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mutability = if fields.is_empty() {
+        quote!()
+    } else {
+        quote!(mut)
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#name #ty_generics> for ::serenity_builder::model::embed::SerenityEmbed #where_clause {
+            fn from(value: &#name #ty_generics) -> Self {
+                let #mutability fields = ::std::vec::Vec::new();
+                #(#fields)*
+
+                ::serenity_builder::model::embed::SerenityEmbed {
+                    title: #title,
+                    description: #description,
+                    url: #url,
+                    timestamp: #timestamp,
+                    color: #color,
+                    footer_text: #footer,
+                    footer_icon_url: ::core::option::Option::None,
+                    image_url: #image,
+                    thumbnail_url: #thumbnail,
+                    author_name: #author,
+                    author_url: ::core::option::Option::None,
+                    author_icon_url: ::core::option::Option::None,
+                    fields: if fields.is_empty() {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(fields)
+                    },
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn expand_error(input: DeriveInput) -> String {
+        expand(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_expand() {
+        let input: DeriveInput = parse_quote! {
+            struct MatchResult {
+                #[embed(title)]
+                name: String,
+                #[embed(field, name = "Score", inline)]
+                score: u32,
+                #[embed(color)]
+                color: Option<u32>,
+                #[embed(skip)]
+                internal_id: u64,
+            }
+        };
+
+        let expanded = expand(&input).unwrap().to_string();
+        assert!(expanded.contains("From < & MatchResult >"));
+        assert!(expanded.contains("\"Score\""));
+        assert!(!expanded.contains("internal_id"));
+    }
+
+    #[test]
+    fn test_expand_misuse() {
+        assert!(
+            expand_error(parse_quote! {
+                struct Duplicate {
+                    #[embed(title)]
+                    a: String,
+                    #[embed(title)]
+                    b: String,
+                }
+            })
+            .contains("duplicate `#[embed(title)]`")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                struct Combined {
+                    #[embed(title, skip)]
+                    a: String,
+                }
+            })
+            .contains("cannot be combined")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                struct Unknown {
+                    #[embed(subtitle)]
+                    a: String,
+                }
+            })
+            .contains("unknown embed attribute")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                struct Inline {
+                    #[embed(title, inline)]
+                    a: String,
+                }
+            })
+            .contains("can only be used with `field`")
+        );
+        assert!(
+            expand_error(parse_quote! {
+                enum NotAStruct { A }
+            })
+            .contains("only be derived for structs")
+        );
+    }
+
+    #[test]
+    fn test_expand_too_many_fields() {
+        let fields = (0..26).map(|i| quote::format_ident!("field_{i}"));
+        let input: DeriveInput = parse_quote! {
+            struct TooMany {
+                #(#fields: u32,)*
+            }
+        };
+
+        assert!(expand_error(input).contains("at most 25 fields"));
+    }
+}
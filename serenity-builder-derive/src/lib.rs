@@ -0,0 +1,129 @@
+//! Derive macros for [serenity-builder](https://docs.rs/serenity-builder).
+//!
+//! Use them through the `derive` feature of serenity-builder instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, GenericArgument, PathArguments, Type, parse_macro_input};
+
+mod command;
+mod embed;
+
+/// The `T` of a type written as `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Derive `From<&T>` for `SerenityEmbed`, turning a struct into an embed.
+///
+/// Every field becomes an embed field unless an attribute says otherwise:
+///
+/// | Attribute | Effect |
+/// | --------- | ------ |
+/// | `#[embed(title)]` | The title of the embed. |
+/// | `#[embed(description)]` | The description of the embed. |
+/// | `#[embed(url)]` | The url of the embed. |
+/// | `#[embed(author)]` | The author name of the embed. |
+/// | `#[embed(footer)]` | The footer text of the embed. |
+/// | `#[embed(image)]` | The image url of the embed. |
+/// | `#[embed(thumbnail)]` | The thumbnail url of the embed. |
+/// | `#[embed(color)]` | The color of the embed. The type must implement `Into<SerenityColor>`, e.g. `u32`. |
+/// | `#[embed(timestamp)]` | The timestamp of the embed. The type must implement `Into<Timestamp>`. |
+/// | `#[embed(field, name = "Score", inline)]` | An embed field. `name` defaults to the name of the struct field. |
+/// | `#[embed(skip)]` | The field is not used. |
+///
+/// Text values are converted with `ToString`, and `Option` fields are left out when they are `None`.
+/// Misuse, such as two titles or more than 25 embed fields, is reported at compile time.
+///
+/// ```rs
+/// #[derive(IntoSerenityEmbed)]
+/// struct MatchResult {
+///     #[embed(title)]
+///     name: String,
+///     #[embed(field, name = "Score", inline)]
+///     score: u32,
+///     #[embed(color)]
+///     color: u32,
+///     #[embed(skip)]
+///     internal_id: u64,
+/// }
+///
+/// let embed = SerenityEmbed::from(&result);
+/// ```
+#[proc_macro_derive(IntoSerenityEmbed, attributes(embed))]
+pub fn derive_into_serenity_embed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    embed::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `SerenityCommandDefinition`, describing a slash command and parsing its options with one struct.
+///
+/// Each field is an option of the command. `Option<T>` fields are optional, all others are required and must come first.
+/// The type of a field decides the type of the option: `String`, `i64`, `f64`, `bool`, `UserId`, `ChannelId`, `RoleId`, `GenericId` (mentionable), `AttachmentId`,
+/// or an enum deriving [SerenityCommandChoice].
+///
+/// | Attribute | Effect |
+/// | --------- | ------ |
+/// | `#[command(name = "ban")]` | On the struct: the name of the command. Defaults to the struct name in kebab-case. |
+/// | `#[command(description = "...")]` | On the struct and on every field: the description of the command or option. Required. |
+/// | `#[command(name = "member")]` | On a field: the name of the option. Defaults to the field name. |
+/// | `#[command(min = 0, max = 7)]` | On a field: the range of an integer or number option. |
+/// | `#[command(min_length = 1, max_length = 512)]` | On a field: the length of a string option. |
+///
+/// Invalid names, missing descriptions, required options after optional ones and more than 25 options are reported at compile time.
+///
+/// ```rs
+/// #[derive(SerenityCommand)]
+/// #[command(name = "ban", description = "Ban a member")]
+/// struct Ban {
+///     #[command(description = "The member to ban")]
+///     member: UserId,
+///     #[command(description = "How many days of messages to delete", min = 0, max = 7)]
+///     days: Option<i64>,
+/// }
+///
+/// let command = Ban::command().convert()?;
+/// let ban = Ban::parse_interaction(&interaction)?;
+/// ```
+#[proc_macro_derive(SerenityCommand, attributes(command))]
+pub fn derive_serenity_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    command::expand_command(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `SerenityCommandOptionValue` for an enum, making its variants the choices of a string option.
+///
+/// Only unit variants are supported. The name shown to the user and the value sent to the bot default to the variant name,
+/// and can be changed with `#[choice(name = "Soft ban", value = "soft")]`.
+///
+/// ```rs
+/// #[derive(SerenityCommandChoice)]
+/// enum Mode {
+///     #[choice(name = "Soft ban")]
+///     Soft,
+///     Hard,
+/// }
+/// ```
+#[proc_macro_derive(SerenityCommandChoice, attributes(choice))]
+pub fn derive_serenity_command_choice(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    command::expand_choice(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
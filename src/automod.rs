@@ -0,0 +1,675 @@
+use std::time::Duration;
+
+use serenity::all::EditAutoModRule;
+use serenity::json::{Value, json};
+use serenity::model::guild::automod::{Action, Trigger};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::automod::{SerenityAutoModAction, SerenityAutoModRule, SerenityAutoModTrigger};
+
+/// Errors that can occur when converting a [SerenityAutoModRule] to a [serenity::all::EditAutoModRule].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityAutoModRuleConvertError {
+    /**
+     * This occurs when the rule name is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when a list (keywords, regex patterns, allow list, exempt roles or exempt channels) has more entries than the Discord API allows.
+     * The name of the offending list is included.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The `{list}` list has too many entries: {actual} entries (limit: {limit}).")]
+    TooManyEntries {
+        list: &'static str,
+        actual: usize,
+        limit: usize,
+    },
+    /**
+     * This occurs when an entry of a list (keywords, regex patterns or allow list) is longer than the Discord API allows.
+     * The name of the offending list and the index of the entry are included.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("Entry #{} of the `{list}` list is too long: {actual} characters (limit: {limit}).", .index + 1)]
+    TooLongEntry {
+        list: &'static str,
+        index: usize,
+        actual: usize,
+        limit: usize,
+    },
+    /**
+     * This occurs when the mention limit exceeds 50, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The mention limit is too large: {actual} (limit: {limit}).")]
+    TooLargeMentionLimit { actual: u8, limit: u8 },
+    /// This occurs when no action is set. Discord requires at least one action.
+    #[error("At least one action is required.")]
+    MissingActions,
+    /**
+     * This occurs when an action is not applicable to the trigger.
+     * The name and the index of the offending action are included.
+     *
+     * For the actions applicable to each trigger, see [crate::model::automod::SerenityAutoModAction].
+     */
+    #[error("The `{action}` action is not applicable to this trigger.")]
+    InapplicableAction { action: &'static str, index: usize },
+    /**
+     * This occurs when the custom message of a block action exceeds 150 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The custom message is too long: {actual} characters (limit: {limit}).")]
+    TooLongCustomMessage {
+        index: usize,
+        actual: usize,
+        limit: usize,
+    },
+    /**
+     * This occurs when the timeout duration exceeds 2419200 seconds (28 days), which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The timeout is too long: {actual} seconds (limit: {limit}).")]
+    TooLongTimeout {
+        index: usize,
+        actual: u32,
+        limit: u32,
+    },
+    /// This occurs when [SerenityAutoModRule::convert] is used for a member profile rule, which Serenity's [EditAutoModRule] cannot send.
+    #[error("Serenity cannot send member profile rules. Use `convert_body` for them.")]
+    UnsupportedTrigger,
+}
+
+impl SerenityAutoModRuleConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. }
+            | Self::TooLongEntry { .. }
+            | Self::TooLongCustomMessage { .. } => ErrorKind::Length,
+            Self::TooManyEntries { .. } => ErrorKind::Count,
+            Self::TooLargeMentionLimit { .. } | Self::TooLongTimeout { .. } => ErrorKind::Range,
+            Self::MissingActions => ErrorKind::Missing,
+            Self::InapplicableAction { .. } | Self::UnsupportedTrigger => ErrorKind::Inapplicable,
+        }
+    }
+
+    /// The path of the offending value within the rule, e.g. `trigger.keywords[3]` or `actions[0].custom_message`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name".to_string(),
+            Self::TooManyEntries { list, .. } => list_path(list),
+            Self::TooLongEntry { list, index, .. } => format!("{}[{index}]", list_path(list)),
+            Self::TooLargeMentionLimit { .. } => "trigger.mention_limit".to_string(),
+            Self::MissingActions => "actions".to_string(),
+            Self::UnsupportedTrigger => "trigger".to_string(),
+            Self::InapplicableAction { index, .. } | Self::TooLongTimeout { index, .. } => {
+                format!("actions[{index}]")
+            }
+            Self::TooLongCustomMessage { index, .. } => {
+                format!("actions[{index}].custom_message")
+            }
+        }
+    }
+}
+
+/// Exempt lists belong to the rule itself, the other lists to its trigger.
+fn list_path(list: &str) -> String {
+    match list {
+        "exempt_roles" | "exempt_channels" => list.to_string(),
+        _ => format!("trigger.{list}"),
+    }
+}
+
+impl SerenityAutoModRule {
+    /// Convert the auto-moderation rule structure created in Builder into a model usable in Serenity.
+    ///
+    /// Serenity uses [serenity::all::EditAutoModRule] for both creating and editing rules.
+    ///
+    /// ```rs
+    /// let rule = SerenityAutoModRule::builder()
+    ///   .name("No spam")
+    ///   .trigger(SerenityAutoModTrigger::Spam)
+    ///   .actions(vec![SerenityAutoModAction::Block { custom_message: None }])
+    ///   .build();
+    ///
+    /// let serenity_rule = rule.convert()?; // Result<EditAutoModRule, SerenityAutoModRuleConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityAutoModRule using the builder
+    /// let rule = SerenityAutoModRule::builder()
+    ///   .name("Banned words")
+    ///   .trigger(SerenityAutoModTrigger::Keyword {
+    ///       keywords: vec!["*badword*".to_string()],
+    ///       regex_patterns: vec![],
+    ///       allow_list: vec![],
+    ///   })
+    ///   .actions(vec![
+    ///       SerenityAutoModAction::Block { custom_message: Some("Watch your language!".to_string()) },
+    ///       SerenityAutoModAction::Timeout(60),
+    ///   ])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's EditAutoModRule
+    /// let serenity_rule = rule.convert()?; // Result<EditAutoModRule, SerenityAutoModRuleConvertError>
+    ///
+    /// // 3. Use the converted rule in your bot
+    /// guild_id.create_automod_rule(&ctx.http, serenity_rule).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityAutoModRuleConvertError::InvalidNameLength]: The name is empty or exceeds 100 characters.
+    /// - [SerenityAutoModRuleConvertError::TooManyEntries]: A list has more entries than allowed.
+    /// - [SerenityAutoModRuleConvertError::TooLongEntry]: An entry of a list is longer than allowed.
+    /// - [SerenityAutoModRuleConvertError::TooLargeMentionLimit]: The mention limit exceeds 50.
+    /// - [SerenityAutoModRuleConvertError::MissingActions]: No action is set.
+    /// - [SerenityAutoModRuleConvertError::InapplicableAction]: An action is not applicable to the trigger.
+    /// - [SerenityAutoModRuleConvertError::TooLongCustomMessage]: The custom message exceeds 150 characters.
+    /// - [SerenityAutoModRuleConvertError::TooLongTimeout]: The timeout exceeds 28 days.
+    /// - [SerenityAutoModRuleConvertError::UnsupportedTrigger]: The trigger is [SerenityAutoModTrigger::MemberProfile]. Use [SerenityAutoModRule::convert_body] instead.
+    pub fn convert(&self) -> Result<EditAutoModRule<'static>, SerenityAutoModRuleConvertError> {
+        if matches!(self.trigger, SerenityAutoModTrigger::MemberProfile { .. }) {
+            return Err(SerenityAutoModRuleConvertError::UnsupportedTrigger);
+        }
+        self.convert_rule()
+    }
+
+    /// Convert the auto-moderation rule into a request body usable in Serenity, including member profile rules.
+    ///
+    /// Serenity's [EditAutoModRule] cannot represent [SerenityAutoModTrigger::MemberProfile] triggers and [SerenityAutoModAction::BlockMemberInteraction] actions.
+    /// The converted value is therefore the JSON body to pass to [serenity::http::Http::create_automod_rule].
+    ///
+    /// ```rs
+    /// let rule = SerenityAutoModRule::builder()
+    ///   .name("No ads in names")
+    ///   .trigger(SerenityAutoModTrigger::MemberProfile {
+    ///       keywords: vec!["*discord.gg*".to_string()],
+    ///       regex_patterns: vec![],
+    ///       allow_list: vec![],
+    ///   })
+    ///   .actions(vec![SerenityAutoModAction::BlockMemberInteraction])
+    ///   .build();
+    ///
+    /// let body = rule.convert_body()?; // Result<Value, SerenityAutoModRuleConvertError>
+    /// ctx.http.create_automod_rule(guild_id, &body, None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the errors of [SerenityAutoModRule::convert] except [SerenityAutoModRuleConvertError::UnsupportedTrigger].
+    pub fn convert_body(&self) -> Result<Value, SerenityAutoModRuleConvertError> {
+        let mut body = json!(self.convert_rule()?);
+        if matches!(self.trigger, SerenityAutoModTrigger::MemberProfile { .. }) {
+            // MEMBER_PROFILE triggers on MEMBER_UPDATE events. The metadata has the same shape as a keyword trigger.
+            body["trigger_type"] = json!(6);
+            body["event_type"] = json!(2);
+        }
+        for (index, action) in self.actions.iter().enumerate() {
+            if matches!(action, SerenityAutoModAction::BlockMemberInteraction) {
+                body["actions"][index] = json!({ "type": 4 });
+            }
+        }
+        Ok(body)
+    }
+
+    /// Validate the rule and convert it. Member profile triggers are carried as keyword triggers and fixed up by [SerenityAutoModRule::convert_body].
+    fn convert_rule(&self) -> Result<EditAutoModRule<'static>, SerenityAutoModRuleConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if !(1..=100).contains(&name_length) {
+            return Err(SerenityAutoModRuleConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let trigger = match &self.trigger {
+            SerenityAutoModTrigger::Keyword {
+                keywords,
+                regex_patterns,
+                allow_list,
+            }
+            | SerenityAutoModTrigger::MemberProfile {
+                keywords,
+                regex_patterns,
+                allow_list,
+            } => {
+                check_list(keywords, 1000, 60, "keywords")?;
+                check_list(regex_patterns, 10, 260, "regex_patterns")?;
+                check_list(allow_list, 100, 60, "allow_list")?;
+                Trigger::Keyword {
+                    strings: keywords.clone(),
+                    regex_patterns: regex_patterns.clone(),
+                    allow_list: allow_list.clone(),
+                }
+            }
+            SerenityAutoModTrigger::Spam => Trigger::Spam,
+            SerenityAutoModTrigger::KeywordPreset {
+                presets,
+                allow_list,
+            } => {
+                check_list(allow_list, 1000, 60, "allow_list")?;
+                Trigger::KeywordPreset {
+                    presets: presets.iter().map(|&p| p.into()).collect(),
+                    allow_list: allow_list.clone(),
+                }
+            }
+            SerenityAutoModTrigger::MentionSpam { mention_limit } => {
+                if *mention_limit > 50 {
+                    return Err(SerenityAutoModRuleConvertError::TooLargeMentionLimit {
+                        actual: *mention_limit,
+                        limit: 50,
+                    });
+                }
+                Trigger::MentionSpam {
+                    mention_total_limit: *mention_limit,
+                }
+            }
+        };
+
+        if self.actions.is_empty() {
+            return Err(SerenityAutoModRuleConvertError::MissingActions);
+        }
+
+        let mut actions = Vec::with_capacity(self.actions.len());
+        for (index, action) in self.actions.iter().enumerate() {
+            let action = match action {
+                SerenityAutoModAction::Block { custom_message } => {
+                    if matches!(self.trigger, SerenityAutoModTrigger::MemberProfile { .. }) {
+                        return Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                            action: "Block",
+                            index,
+                        });
+                    }
+                    if let Some(message) = custom_message {
+                        let length = message.encode_utf16().count();
+                        if length > 150 {
+                            return Err(SerenityAutoModRuleConvertError::TooLongCustomMessage {
+                                index,
+                                actual: length,
+                                limit: 150,
+                            });
+                        }
+                    }
+                    Action::BlockMessage {
+                        custom_message: custom_message.clone(),
+                    }
+                }
+                SerenityAutoModAction::Alert(channel_id) => Action::Alert(*channel_id),
+                SerenityAutoModAction::Timeout(seconds) => {
+                    if !matches!(
+                        self.trigger,
+                        SerenityAutoModTrigger::Keyword { .. }
+                            | SerenityAutoModTrigger::MentionSpam { .. }
+                    ) {
+                        return Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                            action: "Timeout",
+                            index,
+                        });
+                    }
+                    if *seconds > 2419200 {
+                        return Err(SerenityAutoModRuleConvertError::TooLongTimeout {
+                            index,
+                            actual: *seconds,
+                            limit: 2419200,
+                        });
+                    }
+                    Action::Timeout(Duration::from_secs((*seconds).into()))
+                }
+                SerenityAutoModAction::BlockMemberInteraction => {
+                    if !matches!(self.trigger, SerenityAutoModTrigger::MemberProfile { .. }) {
+                        return Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                            action: "BlockMemberInteraction",
+                            index,
+                        });
+                    }
+                    // Replaced by the BLOCK_MEMBER_INTERACTION action in `convert_body`.
+                    Action::Unknown(4)
+                }
+            };
+            actions.push(action);
+        }
+
+        let mut rule = EditAutoModRule::new()
+            .name(&self.name)
+            .trigger(trigger)
+            .actions(actions)
+            .enabled(self.enabled);
+
+        if let Some(roles) = &self.exempt_roles {
+            if roles.len() > 20 {
+                return Err(SerenityAutoModRuleConvertError::TooManyEntries {
+                    list: "exempt_roles",
+                    actual: roles.len(),
+                    limit: 20,
+                });
+            }
+            rule = rule.exempt_roles(roles.clone());
+        }
+
+        if let Some(channels) = &self.exempt_channels {
+            if channels.len() > 50 {
+                return Err(SerenityAutoModRuleConvertError::TooManyEntries {
+                    list: "exempt_channels",
+                    actual: channels.len(),
+                    limit: 50,
+                });
+            }
+            rule = rule.exempt_channels(channels.clone());
+        }
+
+        Ok(rule)
+    }
+}
+
+fn check_list(
+    list: &[String],
+    max_entries: usize,
+    max_length: usize,
+    name: &'static str,
+) -> Result<(), SerenityAutoModRuleConvertError> {
+    if list.len() > max_entries {
+        return Err(SerenityAutoModRuleConvertError::TooManyEntries {
+            list: name,
+            actual: list.len(),
+            limit: max_entries,
+        });
+    }
+    for (index, entry) in list.iter().enumerate() {
+        let length = entry.encode_utf16().count();
+        if length > max_length {
+            return Err(SerenityAutoModRuleConvertError::TooLongEntry {
+                list: name,
+                index,
+                actual: length,
+                limit: max_length,
+            });
+        }
+    }
+    Ok(())
+}
+
+impl_convert!(SerenityAutoModRule => EditAutoModRule<'static>, SerenityAutoModRuleConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{ChannelId, RoleId};
+    use serenity::model::guild::automod::KeywordPresetType;
+
+    use super::*;
+    use crate::model::automod::SerenityAutoModKeywordPreset;
+
+    static MOCK_NAME: &str = "Banned words";
+    static MOCK_TEXT: &str = "Watch your language!";
+    static MOCK_ID: u64 = 123456789012345678;
+
+    fn keyword_trigger(keywords: Vec<String>) -> SerenityAutoModTrigger {
+        SerenityAutoModTrigger::Keyword {
+            keywords,
+            regex_patterns: vec![],
+            allow_list: vec![],
+        }
+    }
+
+    #[test]
+    fn test_automod_rule_conversion() {
+        // serenity-builder
+        let mock_rule = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(keyword_trigger(vec!["*badword*".to_string()]))
+            .actions(vec![
+                SerenityAutoModAction::Block {
+                    custom_message: Some(MOCK_TEXT.to_string()),
+                },
+                SerenityAutoModAction::Alert(ChannelId::new(MOCK_ID)),
+                SerenityAutoModAction::Timeout(60),
+            ])
+            .exempt_roles(vec![RoleId::new(MOCK_ID)])
+            .build();
+        // serenity
+        let serenity_rule = EditAutoModRule::new()
+            .name(MOCK_NAME)
+            .trigger(Trigger::Keyword {
+                strings: vec!["*badword*".to_string()],
+                regex_patterns: vec![],
+                allow_list: vec![],
+            })
+            .actions(vec![
+                Action::BlockMessage {
+                    custom_message: Some(MOCK_TEXT.to_string()),
+                },
+                Action::Alert(ChannelId::new(MOCK_ID)),
+                Action::Timeout(Duration::from_secs(60)),
+            ])
+            .enabled(true)
+            .exempt_roles(vec![RoleId::new(MOCK_ID)]);
+
+        let converted = mock_rule.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_rule).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_automod_rule_conversion_keyword_preset() {
+        // serenity-builder
+        let mock_rule = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::KeywordPreset {
+                presets: vec![SerenityAutoModKeywordPreset::Slurs],
+                allow_list: vec![],
+            })
+            .actions(vec![SerenityAutoModAction::Block {
+                custom_message: None,
+            }])
+            .enabled(false)
+            .build();
+        // serenity
+        let serenity_rule = EditAutoModRule::new()
+            .name(MOCK_NAME)
+            .trigger(Trigger::KeywordPreset {
+                presets: vec![KeywordPresetType::Slurs],
+                allow_list: vec![],
+            })
+            .actions(vec![Action::BlockMessage {
+                custom_message: None,
+            }])
+            .enabled(false);
+
+        let converted = mock_rule.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_rule).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_automod_rule_conversion_keyword_limits() {
+        let too_many = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(keyword_trigger(vec!["a".to_string(); 1001]))
+            .actions(vec![SerenityAutoModAction::Timeout(60)])
+            .build();
+        let too_long = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(keyword_trigger(vec!["a".repeat(61)]))
+            .actions(vec![SerenityAutoModAction::Timeout(60)])
+            .build();
+
+        assert!(matches!(
+            too_many.convert(),
+            Err(SerenityAutoModRuleConvertError::TooManyEntries {
+                list: "keywords",
+                actual: 1001,
+                limit: 1000
+            })
+        ));
+
+        let error = too_long.convert().unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityAutoModRuleConvertError::TooLongEntry {
+                list: "keywords",
+                index: 0,
+                actual: 61,
+                limit: 60
+            }
+        ));
+        assert_eq!(error.field_path(), "trigger.keywords[0]");
+    }
+
+    #[test]
+    fn test_automod_rule_conversion_invalid_timeout() {
+        let inapplicable = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::Spam)
+            .actions(vec![SerenityAutoModAction::Timeout(60)])
+            .build();
+        let too_long = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::MentionSpam { mention_limit: 5 })
+            .actions(vec![SerenityAutoModAction::Timeout(2419201)])
+            .build();
+
+        assert!(matches!(
+            inapplicable.convert(),
+            Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                action: "Timeout",
+                index: 0
+            })
+        ));
+        assert!(matches!(
+            too_long.convert(),
+            Err(SerenityAutoModRuleConvertError::TooLongTimeout {
+                actual: 2419201,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_automod_rule_conversion_missing_actions() {
+        let mock_rule = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::Spam)
+            .actions(vec![])
+            .build();
+
+        let converted = mock_rule.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityAutoModRuleConvertError::MissingActions)
+        ));
+    }
+
+    #[test]
+    fn test_automod_rule_member_profile() {
+        let mock_rule = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::MemberProfile {
+                keywords: vec!["*discord.gg*".to_string()],
+                regex_patterns: vec![],
+                allow_list: vec![],
+            })
+            .actions(vec![
+                SerenityAutoModAction::BlockMemberInteraction,
+                SerenityAutoModAction::Alert(ChannelId::new(MOCK_ID)),
+            ])
+            .build();
+
+        let body = mock_rule.convert_body().unwrap();
+
+        assert_eq!(body["trigger_type"], json!(6));
+        assert_eq!(body["event_type"], json!(2));
+        assert_eq!(
+            body["trigger_metadata"]["keyword_filter"],
+            json!(["*discord.gg*"])
+        );
+        assert_eq!(body["actions"][0], json!({ "type": 4 }));
+        assert_eq!(body["actions"][1]["type"], json!(2));
+        assert!(matches!(
+            mock_rule.convert(),
+            Err(SerenityAutoModRuleConvertError::UnsupportedTrigger)
+        ));
+    }
+
+    #[test]
+    fn test_automod_rule_member_profile_actions() {
+        let block = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::MemberProfile {
+                keywords: vec!["a".to_string()],
+                regex_patterns: vec![],
+                allow_list: vec![],
+            })
+            .actions(vec![SerenityAutoModAction::Block {
+                custom_message: None,
+            }])
+            .build();
+        let block_interaction = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::Spam)
+            .actions(vec![SerenityAutoModAction::BlockMemberInteraction])
+            .build();
+
+        assert!(matches!(
+            block.convert_body(),
+            Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                action: "Block",
+                index: 0
+            })
+        ));
+        assert!(matches!(
+            block_interaction.convert_body(),
+            Err(SerenityAutoModRuleConvertError::InapplicableAction {
+                action: "BlockMemberInteraction",
+                index: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_automod_rule_body_matches_conversion() {
+        let mock_rule = SerenityAutoModRule::builder()
+            .name(MOCK_NAME)
+            .trigger(SerenityAutoModTrigger::Spam)
+            .actions(vec![SerenityAutoModAction::Block {
+                custom_message: None,
+            }])
+            .build();
+
+        assert_eq!(
+            mock_rule.convert_body().unwrap(),
+            serde_json::to_value(mock_rule.convert().unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,638 @@
+use serenity::all::{CreateChannel, EditChannel, ForumEmoji, ForumLayoutType, PermissionOverwrite};
+use serenity::json::{Value, json};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::channel::{SerenityChannel, SerenityChannelType};
+use crate::model::forum::SerenityForumTagEmoji;
+
+/// Errors that can occur when converting a [SerenityChannel] to a [serenity::all::CreateChannel] or [serenity::all::EditChannel].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityChannelConvertError {
+    /**
+     * This occurs when the channel name is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the channel topic exceeds 1024 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The topic is too long: {actual} characters (limit: {limit}).")]
+    TooLongTopic { actual: usize, limit: usize },
+    /**
+     * This occurs when the slowmode exceeds 21600 seconds, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The slowmode is too long: {actual} seconds (limit: {limit}).")]
+    TooLongSlowmode { actual: u16, limit: u16 },
+    /**
+     * This occurs when the bitrate is not between 8000 and 384000, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The bitrate must be between {min} and {max}, but is {actual}.")]
+    InvalidBitrate { actual: u32, min: u32, max: u32 },
+    /**
+     * This occurs when the user limit exceeds 99 for voice channels or 10000 for stage channels, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The user limit is too large for this channel type: {actual} (limit: {limit}).")]
+    TooManyUserLimit { actual: u32, limit: u32 },
+    /**
+     * This occurs when a value is set that does not apply to the channel type.
+     * The name of the offending value is included.
+     *
+     * For the values applicable to each channel type, see [crate::model::channel::SerenityChannel].
+     */
+    #[error("The `{field}` value is not applicable to this channel type.")]
+    InapplicableField { field: &'static str },
+    /**
+     * This occurs when `default_forum_layout` is set for [SerenityChannel::convert].
+     * Discord accepts this value when creating a channel, but Serenity's [CreateChannel] has no way to set it.
+     * Use [SerenityChannel::convert_body] to create a channel with a default forum layout.
+     */
+    #[error(
+        "Serenity's channel builder cannot set the default forum layout. Use `convert_body` for it."
+    )]
+    ForumLayoutOnCreate,
+}
+
+impl SerenityChannelConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. } | Self::TooLongTopic { .. } => ErrorKind::Length,
+            Self::TooLongSlowmode { .. }
+            | Self::InvalidBitrate { .. }
+            | Self::TooManyUserLimit { .. } => ErrorKind::Range,
+            Self::InapplicableField { .. } | Self::ForumLayoutOnCreate => ErrorKind::Inapplicable,
+        }
+    }
+
+    /// The path of the offending value within the channel.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name",
+            Self::TooLongTopic { .. } => "topic",
+            Self::TooLongSlowmode { .. } => "slowmode",
+            Self::InvalidBitrate { .. } => "bitrate",
+            Self::TooManyUserLimit { .. } => "user_limit",
+            Self::InapplicableField { field } => field,
+            Self::ForumLayoutOnCreate => "default_forum_layout",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityChannel {
+    /// Convert the channel structure created in Builder into a model usable in Serenity for creating a channel.
+    ///
+    /// ```rs
+    /// let channel = SerenityChannel::builder()
+    ///   .name("general")
+    ///   .build();
+    ///
+    /// let serenity_channel = channel.convert()?; // Result<CreateChannel, SerenityChannelConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityChannel using the builder
+    /// let channel = SerenityChannel::builder()
+    ///   .name("voice")
+    ///   .kind(SerenityChannelType::Voice)
+    ///   .user_limit(10_u32)
+    ///   .permission_overwrites(vec![
+    ///       SerenityPermissionOverwrite::builder()
+    ///           .target(SerenityPermissionOverwriteTarget::Role(guild_id.everyone_role()))
+    ///           .deny(Permissions::CONNECT)
+    ///           .build(),
+    ///   ])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateChannel
+    /// let serenity_channel = channel.convert()?; // Result<CreateChannel, SerenityChannelConvertError>
+    ///
+    /// // 3. Use the converted channel in your bot
+    /// guild_id.create_channel(&ctx.http, serenity_channel).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityChannelConvertError::InvalidNameLength]: The name is empty or exceeds 100 characters.
+    /// - [SerenityChannelConvertError::TooLongTopic]: The topic exceeds 1024 characters.
+    /// - [SerenityChannelConvertError::TooLongSlowmode]: The slowmode exceeds 21600 seconds.
+    /// - [SerenityChannelConvertError::InvalidBitrate]: The bitrate is not between 8000 and 384000.
+    /// - [SerenityChannelConvertError::TooManyUserLimit]: The user limit exceeds the maximum for the channel type.
+    /// - [SerenityChannelConvertError::InapplicableField]: A value is set that does not apply to the channel type.
+    /// - [SerenityChannelConvertError::ForumLayoutOnCreate]: `default_forum_layout` is set. Use [SerenityChannel::convert_body] instead.
+    pub fn convert(&self) -> Result<CreateChannel<'static>, SerenityChannelConvertError> {
+        if self.default_forum_layout.is_some() {
+            return Err(SerenityChannelConvertError::ForumLayoutOnCreate);
+        }
+        self.convert_create()
+    }
+
+    /// Convert the channel structure created in Builder into a request body usable in Serenity for creating a channel, including the default forum layout.
+    ///
+    /// Serenity's [CreateChannel] cannot set `default_forum_layout`.
+    /// The converted value is therefore the JSON body to pass to [serenity::http::Http::create_channel].
+    ///
+    /// ```rs
+    /// let channel = SerenityChannel::builder()
+    ///   .name("gallery")
+    ///   .kind(SerenityChannelType::Forum)
+    ///   .default_forum_layout(SerenityForumLayout::Gallery)
+    ///   .build();
+    ///
+    /// let body = channel.convert_body()?; // Result<Value, SerenityChannelConvertError>
+    /// ctx.http.create_channel(guild_id, &body, None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the same errors as [SerenityChannel::convert], except [SerenityChannelConvertError::ForumLayoutOnCreate].
+    pub fn convert_body(&self) -> Result<Value, SerenityChannelConvertError> {
+        let mut body = json!(self.convert_create()?);
+        if let Some(layout) = self.default_forum_layout {
+            body["default_forum_layout"] = json!(ForumLayoutType::from(layout));
+        }
+        Ok(body)
+    }
+
+    /// Validate the channel and convert it into a [CreateChannel] without the default forum layout.
+    fn convert_create(&self) -> Result<CreateChannel<'static>, SerenityChannelConvertError> {
+        self.validate()?;
+
+        let mut channel = CreateChannel::new(&self.name).kind(self.kind.into());
+
+        if let Some(topic) = &self.topic {
+            channel = channel.topic(topic);
+        }
+
+        if let Some(position) = self.position {
+            channel = channel.position(position);
+        }
+
+        if let Some(nsfw) = self.nsfw {
+            channel = channel.nsfw(nsfw);
+        }
+
+        if let Some(slowmode) = self.slowmode {
+            channel = channel.rate_limit_per_user(slowmode);
+        }
+
+        if let Some(bitrate) = self.bitrate {
+            channel = channel.bitrate(bitrate);
+        }
+
+        if let Some(user_limit) = self.user_limit {
+            channel = channel.user_limit(user_limit);
+        }
+
+        if let Some(parent_id) = self.parent_id {
+            channel = channel.category(parent_id);
+        }
+
+        if let Some(emoji) = &self.default_reaction {
+            channel = channel.default_reaction_emoji(forum_emoji(emoji));
+        }
+
+        if let Some(sort_order) = self.default_sort_order {
+            channel = channel.default_sort_order(sort_order.into());
+        }
+
+        if let Some(rtc_region) = &self.rtc_region {
+            channel = channel.rtc_region(rtc_region.clone());
+        }
+
+        if let Some(video_quality) = self.video_quality {
+            channel = channel.video_quality_mode(video_quality.into());
+        }
+
+        if let Some(overwrites) = &self.permission_overwrites {
+            channel = channel.permissions(overwrites.iter().map(PermissionOverwrite::from));
+        }
+
+        Ok(channel)
+    }
+
+    /// Convert the channel structure created in Builder into a model usable in Serenity for editing an existing channel.
+    ///
+    /// All values that are set are sent, so the structure can be kept as the desired state of the channel.
+    /// The channel type is only sent for text and announcement channels, since Discord only supports converting between these two.
+    ///
+    /// ```rs
+    /// let channel = SerenityChannel::builder()
+    ///   .name("announcements")
+    ///   .kind(SerenityChannelType::Announcement)
+    ///   .build();
+    ///
+    /// let serenity_channel = channel.convert_edit()?; // Result<EditChannel, SerenityChannelConvertError>
+    ///
+    /// channel_id.edit(&ctx.http, serenity_channel).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the same errors as [SerenityChannel::convert], except [SerenityChannelConvertError::ForumLayoutOnCreate].
+    pub fn convert_edit(&self) -> Result<EditChannel<'static>, SerenityChannelConvertError> {
+        self.validate()?;
+
+        let mut channel = EditChannel::new().name(&self.name);
+
+        if matches!(
+            self.kind,
+            SerenityChannelType::Text | SerenityChannelType::Announcement
+        ) {
+            channel = channel.kind(self.kind.into());
+        }
+
+        if let Some(topic) = &self.topic {
+            channel = channel.topic(topic);
+        }
+
+        if let Some(position) = self.position {
+            channel = channel.position(position);
+        }
+
+        if let Some(nsfw) = self.nsfw {
+            channel = channel.nsfw(nsfw);
+        }
+
+        if let Some(slowmode) = self.slowmode {
+            channel = channel.rate_limit_per_user(slowmode);
+        }
+
+        if let Some(bitrate) = self.bitrate {
+            channel = channel.bitrate(bitrate);
+        }
+
+        if let Some(user_limit) = self.user_limit {
+            channel = channel.user_limit(user_limit);
+        }
+
+        if let Some(parent_id) = self.parent_id {
+            channel = channel.category(parent_id);
+        }
+
+        if let Some(emoji) = &self.default_reaction {
+            channel = channel.default_reaction_emoji(Some(forum_emoji(emoji)));
+        }
+
+        if let Some(sort_order) = self.default_sort_order {
+            channel = channel.default_sort_order(sort_order.into());
+        }
+
+        if let Some(layout) = self.default_forum_layout {
+            channel = channel.default_forum_layout(layout.into());
+        }
+
+        if let Some(rtc_region) = &self.rtc_region {
+            channel = channel.voice_region(Some(rtc_region.clone()));
+        }
+
+        if let Some(video_quality) = self.video_quality {
+            channel = channel.video_quality_mode(video_quality.into());
+        }
+
+        if let Some(overwrites) = &self.permission_overwrites {
+            channel = channel.permissions(overwrites.iter().map(PermissionOverwrite::from));
+        }
+
+        Ok(channel)
+    }
+
+    /// Validate the values shared by [SerenityChannel::convert] and [SerenityChannel::convert_edit].
+    fn validate(&self) -> Result<(), SerenityChannelConvertError> {
+        use SerenityChannelType::*;
+
+        let name_length = self.name.encode_utf16().count();
+        if !(1..=100).contains(&name_length) {
+            return Err(SerenityChannelConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let kind = self.kind;
+        let applicable = |set: bool, types: &[SerenityChannelType], field: &'static str| {
+            if set && !types.contains(&kind) {
+                Err(SerenityChannelConvertError::InapplicableField { field })
+            } else {
+                Ok(())
+            }
+        };
+
+        applicable(self.topic.is_some(), &[Text, Announcement, Forum], "topic")?;
+        applicable(
+            self.nsfw.is_some(),
+            &[Text, Voice, Stage, Announcement, Forum],
+            "nsfw",
+        )?;
+        applicable(
+            self.slowmode.is_some(),
+            &[Text, Voice, Stage, Forum],
+            "slowmode",
+        )?;
+        applicable(self.bitrate.is_some(), &[Voice, Stage], "bitrate")?;
+        applicable(self.user_limit.is_some(), &[Voice, Stage], "user_limit")?;
+        applicable(self.rtc_region.is_some(), &[Voice, Stage], "rtc_region")?;
+        applicable(
+            self.video_quality.is_some(),
+            &[Voice, Stage],
+            "video_quality",
+        )?;
+        applicable(
+            self.parent_id.is_some(),
+            &[Text, Voice, Stage, Announcement, Forum],
+            "parent_id",
+        )?;
+        applicable(
+            self.default_reaction.is_some(),
+            &[Forum],
+            "default_reaction",
+        )?;
+        applicable(
+            self.default_sort_order.is_some(),
+            &[Forum],
+            "default_sort_order",
+        )?;
+        applicable(
+            self.default_forum_layout.is_some(),
+            &[Forum],
+            "default_forum_layout",
+        )?;
+
+        if let Some(topic) = &self.topic {
+            let length = topic.encode_utf16().count();
+            if length > 1024 {
+                return Err(SerenityChannelConvertError::TooLongTopic {
+                    actual: length,
+                    limit: 1024,
+                });
+            }
+        }
+
+        if let Some(slowmode) = self.slowmode
+            && slowmode > 21600
+        {
+            return Err(SerenityChannelConvertError::TooLongSlowmode {
+                actual: slowmode,
+                limit: 21600,
+            });
+        }
+
+        if let Some(bitrate) = self.bitrate
+            && !(8000..=384000).contains(&bitrate)
+        {
+            return Err(SerenityChannelConvertError::InvalidBitrate {
+                actual: bitrate,
+                min: 8000,
+                max: 384000,
+            });
+        }
+
+        if let Some(user_limit) = self.user_limit {
+            let max = if kind == Stage { 10000 } else { 99 };
+            if user_limit > max {
+                return Err(SerenityChannelConvertError::TooManyUserLimit {
+                    actual: user_limit,
+                    limit: max,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn forum_emoji(emoji: &SerenityForumTagEmoji) -> ForumEmoji {
+    match emoji {
+        SerenityForumTagEmoji::Custom(id) => ForumEmoji::Id(*id),
+        SerenityForumTagEmoji::Unicode(name) => ForumEmoji::Name(name.clone()),
+    }
+}
+
+impl_convert!(SerenityChannel => CreateChannel<'static>, SerenityChannelConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{
+        ChannelId, ChannelType, PermissionOverwriteType, Permissions, RoleId, SortOrder,
+        VideoQualityMode,
+    };
+
+    use super::*;
+    use crate::model::channel::{
+        SerenityForumLayout, SerenityForumSortOrder, SerenityPermissionOverwrite,
+        SerenityPermissionOverwriteTarget, SerenityVideoQuality,
+    };
+
+    static MOCK_NAME: &str = "general";
+    static MOCK_TEXT: &str = "This is a test topic.";
+    static MOCK_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_channel_conversion() {
+        let overwrites = vec![
+            SerenityPermissionOverwrite::builder()
+                .target(SerenityPermissionOverwriteTarget::Role(RoleId::new(
+                    MOCK_ID,
+                )))
+                .deny(Permissions::SEND_MESSAGES)
+                .build(),
+        ];
+
+        // serenity-builder
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .topic(MOCK_TEXT)
+            .position(1_u16)
+            .nsfw(false)
+            .slowmode(10_u16)
+            .parent_id(ChannelId::new(MOCK_ID))
+            .permission_overwrites(overwrites)
+            .build();
+        // serenity
+        let serenity_channel = CreateChannel::new(MOCK_NAME)
+            .kind(ChannelType::Text)
+            .topic(MOCK_TEXT)
+            .position(1)
+            .nsfw(false)
+            .rate_limit_per_user(10)
+            .category(ChannelId::new(MOCK_ID))
+            .permissions(vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::SEND_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId::new(MOCK_ID)),
+            }]);
+
+        let converted = mock_channel.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_channel).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_voice_channel_edit_conversion() {
+        // serenity-builder
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Voice)
+            .bitrate(64000_u32)
+            .user_limit(99_u32)
+            .rtc_region("japan")
+            .video_quality(SerenityVideoQuality::Full)
+            .build();
+        // serenity
+        let serenity_channel = EditChannel::new()
+            .name(MOCK_NAME)
+            .bitrate(64000)
+            .user_limit(99)
+            .voice_region(Some("japan".to_string()))
+            .video_quality_mode(VideoQualityMode::Full);
+
+        let converted = mock_channel.convert_edit();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_channel).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_forum_channel_conversion() {
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Forum)
+            .default_reaction(SerenityForumTagEmoji::Unicode("👍".to_string()))
+            .default_sort_order(SerenityForumSortOrder::CreationDate)
+            .default_forum_layout(SerenityForumLayout::Gallery)
+            .build();
+
+        assert!(matches!(
+            mock_channel.convert(),
+            Err(SerenityChannelConvertError::ForumLayoutOnCreate)
+        ));
+
+        let body = mock_channel.convert_body().unwrap();
+        let mut serenity_body = serde_json::to_value(
+            CreateChannel::new(MOCK_NAME)
+                .kind(ChannelType::Forum)
+                .default_reaction_emoji(ForumEmoji::Name("👍".to_string()))
+                .default_sort_order(SortOrder::CreationDate),
+        )
+        .unwrap();
+        serenity_body["default_forum_layout"] = json!(2);
+
+        assert_eq!(body, serenity_body);
+
+        let serenity_channel = EditChannel::new()
+            .name(MOCK_NAME)
+            .default_reaction_emoji(Some(ForumEmoji::Name("👍".to_string())))
+            .default_sort_order(SortOrder::CreationDate)
+            .default_forum_layout(serenity::all::ForumLayoutType::GalleryView);
+
+        let converted = mock_channel.convert_edit();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_channel).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_channel_conversion_inapplicable_field() {
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Category)
+            .topic(MOCK_TEXT)
+            .build();
+
+        let converted = mock_channel.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityChannelConvertError::InapplicableField { field: "topic" })
+        ));
+    }
+
+    #[test]
+    fn test_channel_conversion_too_long_topic() {
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .topic("a".repeat(1025))
+            .build();
+
+        let converted = mock_channel.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityChannelConvertError::TooLongTopic {
+                actual: 1025,
+                limit: 1024
+            })
+        ));
+    }
+
+    #[test]
+    fn test_channel_conversion_user_limit() {
+        let voice = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Voice)
+            .user_limit(100_u32)
+            .build();
+        let stage = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Stage)
+            .user_limit(100_u32)
+            .build();
+
+        assert!(matches!(
+            voice.convert(),
+            Err(SerenityChannelConvertError::TooManyUserLimit {
+                actual: 100,
+                limit: 99
+            })
+        ));
+        assert!(stage.convert().is_ok());
+    }
+
+    #[test]
+    fn test_channel_conversion_invalid_bitrate() {
+        let mock_channel = SerenityChannel::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityChannelType::Voice)
+            .bitrate(1000_u32)
+            .build();
+
+        let converted = mock_channel.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityChannelConvertError::InvalidBitrate { actual: 1000, .. })
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::model::color::SerenityColor;
+
+/// Errors that can occur when parsing a [SerenityColor] from a string.
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityColorParseError {
+    /// This occurs when the string is neither `#RRGGBB`, `RRGGBB` nor the name of a Discord brand color.
+    #[error("{actual:?} is not a color. Use `#RRGGBB`, `RRGGBB` or a Discord color name.")]
+    InvalidFormat { actual: String },
+}
+
+impl SerenityColor {
+    /// Create a color from its red, green and blue components.
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self((r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    /// Create a color from its hue (in degrees), saturation and lightness (both from `0.0` to `1.0`).
+    ///
+    /// The hue wraps around, and saturation and lightness are clamped to their range.
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        let channel = |value: f64| ((value + m) * 255.0).round() as u8;
+
+        Self::from_rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// The red, green and blue components of the color.
+    pub const fn rgb(self) -> (u8, u8, u8) {
+        let value = self.value();
+        ((value >> 16) as u8, (value >> 8) as u8, value as u8)
+    }
+
+    /// Whether the color code fits in 24 bits, as required by Discord.
+    pub const fn is_valid(self) -> bool {
+        self.value() <= Self::MAX
+    }
+}
+
+impl FromStr for SerenityColor {
+    type Err = SerenityColorParseError;
+
+    /// Parse `#RRGGBB`, `RRGGBB` or the name of a Discord brand color, e.g. `"blurple"`. Letters are case-insensitive.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trimmed = text.trim();
+        if let Some((_, color)) = Self::NAMED
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(trimmed))
+        {
+            return Ok(*color);
+        }
+
+        let hex = trimmed.strip_prefix('#').unwrap_or(trimmed);
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SerenityColorParseError::InvalidFormat {
+                actual: text.to_string(),
+            });
+        }
+
+        u32::from_str_radix(hex, 16).map(Self::from).map_err(|_| {
+            SerenityColorParseError::InvalidFormat {
+                actual: text.to_string(),
+            }
+        })
+    }
+}
+
+impl fmt::Display for SerenityColor {
+    /// Format the color as `#RRGGBB`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06X}", self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::Colour;
+    use serenity::json::json;
+
+    use super::*;
+
+    static MOCK_COLOR: u32 = 0x5865F2;
+
+    #[test]
+    fn test_color_sources() {
+        assert_eq!(SerenityColor::from(MOCK_COLOR), SerenityColor::BLURPLE);
+        assert_eq!(
+            SerenityColor::from((0x58, 0x65, 0xF2)),
+            SerenityColor::BLURPLE
+        );
+        assert_eq!(
+            SerenityColor::from(Colour(MOCK_COLOR)),
+            SerenityColor::BLURPLE
+        );
+        assert_eq!(Colour::from(SerenityColor::BLURPLE), Colour(MOCK_COLOR));
+        assert_eq!(
+            SerenityColor::from_hsl(0.0, 1.0, 0.5),
+            SerenityColor::from(0xFF0000)
+        );
+        assert_eq!(
+            SerenityColor::from_hsl(240.0, 1.0, 0.5),
+            SerenityColor::from(0x0000FF)
+        );
+        assert_eq!(SerenityColor::BLURPLE.rgb(), (0x58, 0x65, 0xF2));
+        assert_eq!(SerenityColor::BLURPLE.to_string(), "#5865F2");
+    }
+
+    #[test]
+    fn test_color_parse() {
+        assert_eq!(
+            "#5865f2".parse::<SerenityColor>().unwrap(),
+            SerenityColor::BLURPLE
+        );
+        assert_eq!(
+            "5865F2".parse::<SerenityColor>().unwrap(),
+            SerenityColor::BLURPLE
+        );
+        assert_eq!(
+            "Blurple".parse::<SerenityColor>().unwrap(),
+            SerenityColor::BLURPLE
+        );
+        assert!("#5865F".parse::<SerenityColor>().is_err());
+        assert!("#+5865F".parse::<SerenityColor>().is_err());
+        assert!("purple".parse::<SerenityColor>().is_err());
+    }
+
+    #[test]
+    fn test_color_serde() {
+        let colors: Vec<SerenityColor> =
+            serde_json::from_value(json!(["#5865F2", MOCK_COLOR, "blurple"])).unwrap();
+        assert!(colors.iter().all(|color| *color == SerenityColor::BLURPLE));
+
+        let too_large: SerenityColor = serde_json::from_value(json!(0x1000000)).unwrap();
+        assert!(!too_large.is_valid());
+        assert!(serde_json::from_value::<SerenityColor>(json!(0x100000000_u64)).is_err());
+        assert!(serde_json::from_value::<SerenityColor>(json!(-1)).is_err());
+        assert!(serde_json::from_value::<SerenityColor>(json!("nope")).is_err());
+        assert_eq!(
+            serde_json::to_value(SerenityColor::BLURPLE).unwrap(),
+            json!(MOCK_COLOR)
+        );
+    }
+}
@@ -0,0 +1,1091 @@
+use serenity::all::{
+    AttachmentId, ChannelId, CommandData, CommandDataOption, CommandDataOptionValue,
+    CommandInteraction, CommandOptionType, CreateCommand, CreateCommandOption, GenericId, RoleId,
+    UserId,
+};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::command::{
+    SerenityCommand, SerenityCommandChoice, SerenityCommandChoiceValue, SerenityCommandOption,
+    SerenityCommandOptionLimit,
+};
+
+/// Errors that can occur when converting a [SerenityCommand] to a [serenity::all::CreateCommand].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityCommandConvertError {
+    /**
+     * This occurs when the command name is empty, longer than 32 characters, or contains characters other than lowercase letters, digits, `-`, `_` and `'`, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name {actual:?} is not a valid command name.")]
+    InvalidName { actual: String },
+    /**
+     * This occurs when the command description is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The description must be between {min} and {max} characters, but is {actual}.")]
+    InvalidDescriptionLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when more than 25 options are set, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("There are too many options: {actual} options (limit: {limit}).")]
+    TooManyOptions { actual: usize, limit: usize },
+    /**
+     * This occurs when two options have the same name, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("Option #{} has the same name as an earlier option: {name:?}.", .option + 1)]
+    DuplicateOptionName { option: usize, name: String },
+    /**
+     * This occurs when a required option follows an optional one, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("Option #{} is required, but follows an optional option.", .option + 1)]
+    RequiredAfterOptional { option: usize },
+    /**
+     * This occurs when the name of an option is not a valid name. The same rules as for the command name apply.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name {actual:?} of option #{} is not a valid option name.", .option + 1)]
+    InvalidOptionName { option: usize, actual: String },
+    /**
+     * This occurs when the description of an option is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The description of option #{} must be between {min} and {max} characters, but is {actual}.", .option + 1)]
+    InvalidOptionDescriptionLength {
+        option: usize,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the option type is a subcommand, a subcommand group or unknown.
+     *
+     * Subcommands and subcommand groups are not supported by serenity-builder yet.
+     */
+    #[error("The type of option #{} is not supported.", .option + 1)]
+    UnsupportedOptionType { option: usize },
+    /**
+     * This occurs when a value is set that does not apply to the type of the option, e.g. `min_length` on an integer option.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The {field} of option #{} cannot be set for this option type.", .option + 1)]
+    InapplicableField { option: usize, field: &'static str },
+    /**
+     * This occurs when more than 25 choices are set for an option, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("There are too many choices for option #{}: {actual} choices (limit: {limit}).", .option + 1)]
+    TooManyChoices {
+        option: usize,
+        actual: usize,
+        limit: usize,
+    },
+    /**
+     * This occurs when the name of a choice is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name of choice #{} of option #{} must be between {min} and {max} characters, but is {actual}.", .choice + 1, .option + 1)]
+    InvalidChoiceNameLength {
+        option: usize,
+        choice: usize,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the value of a choice does not match the type of the option.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The value of choice #{} of option #{} does not match the type of the option.", .choice + 1, .option + 1)]
+    MismatchedChoice { option: usize, choice: usize },
+    /**
+     * This occurs when the minimum value of an option is larger than its maximum value.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The minimum value of option #{} ({min}) is larger than the maximum value ({max}).", .option + 1)]
+    InvalidRange {
+        option: usize,
+        min: SerenityCommandOptionLimit,
+        max: SerenityCommandOptionLimit,
+    },
+    /**
+     * This occurs when the minimum or maximum value of an integer option is not an integer.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The {field} of option #{} must be an integer, but is {actual}.", .option + 1)]
+    NonIntegralValue {
+        option: usize,
+        field: &'static str,
+        actual: f64,
+    },
+    /**
+     * This occurs when the minimum or maximum value of an integer option is negative.
+     *
+     * Discord accepts negative limits, but Serenity's [CreateCommandOption] only accepts non-negative integer limits.
+     * Sending the limit as a number instead would turn it into a decimal such as `-5.0`, so Serenity-builder reports an error during conversion.
+     */
+    #[error("The {field} of option #{} must not be negative for an integer option, but is {actual}.", .option + 1)]
+    NegativeIntegerValue {
+        option: usize,
+        field: &'static str,
+        actual: i64,
+    },
+    /**
+     * This occurs when the minimum or maximum length of a string option exceeds 6000, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The {field} of option #{} is too large: {actual} (limit: {limit}).", .option + 1)]
+    TooLargeLength {
+        option: usize,
+        field: &'static str,
+        actual: u16,
+        limit: u16,
+    },
+    /**
+     * This occurs when the minimum length of a string option is larger than its maximum length.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The minimum length of option #{} ({min}) is larger than the maximum length ({max}).", .option + 1)]
+    InvalidLengthRange { option: usize, min: u16, max: u16 },
+}
+
+impl SerenityCommandConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidName { .. }
+            | Self::InvalidOptionName { .. }
+            | Self::NonIntegralValue { .. } => ErrorKind::Format,
+            Self::InvalidDescriptionLength { .. }
+            | Self::InvalidOptionDescriptionLength { .. }
+            | Self::InvalidChoiceNameLength { .. } => ErrorKind::Length,
+            Self::TooManyOptions { .. } | Self::TooManyChoices { .. } => ErrorKind::Count,
+            Self::DuplicateOptionName { .. } | Self::RequiredAfterOptional { .. } => {
+                ErrorKind::Conflict
+            }
+            Self::UnsupportedOptionType { .. }
+            | Self::InapplicableField { .. }
+            | Self::MismatchedChoice { .. } => ErrorKind::Inapplicable,
+            Self::InvalidRange { .. }
+            | Self::NegativeIntegerValue { .. }
+            | Self::TooLargeLength { .. }
+            | Self::InvalidLengthRange { .. } => ErrorKind::Range,
+        }
+    }
+
+    /// The path of the offending value within the command, e.g. `options[1].choices[0].name`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidName { .. } => "name".to_string(),
+            Self::InvalidDescriptionLength { .. } => "description".to_string(),
+            Self::TooManyOptions { .. } => "options".to_string(),
+            Self::DuplicateOptionName { option, .. } | Self::InvalidOptionName { option, .. } => {
+                format!("options[{option}].name")
+            }
+            Self::RequiredAfterOptional { option } => format!("options[{option}].required"),
+            Self::InvalidOptionDescriptionLength { option, .. } => {
+                format!("options[{option}].description")
+            }
+            Self::UnsupportedOptionType { option } => format!("options[{option}].kind"),
+            Self::InapplicableField { option, field }
+            | Self::NonIntegralValue { option, field, .. }
+            | Self::NegativeIntegerValue { option, field, .. }
+            | Self::TooLargeLength { option, field, .. } => format!("options[{option}].{field}"),
+            Self::TooManyChoices { option, .. } => format!("options[{option}].choices"),
+            Self::InvalidChoiceNameLength { option, choice, .. } => {
+                format!("options[{option}].choices[{choice}].name")
+            }
+            Self::MismatchedChoice { option, choice } => {
+                format!("options[{option}].choices[{choice}].value")
+            }
+            Self::InvalidRange { option, .. } => format!("options[{option}].min_value"),
+            Self::InvalidLengthRange { option, .. } => format!("options[{option}].min_length"),
+        }
+    }
+}
+
+impl SerenityCommandOptionLimit {
+    /// The limit as a decimal. Integers beyond 2^53 are rounded.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Number(value) => value,
+        }
+    }
+
+    /// Whether the limit is larger than the other one. Two integers are compared exactly.
+    fn is_larger_than(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Integer(value), Self::Integer(other)) => value > other,
+            _ => self.as_f64() > other.as_f64(),
+        }
+    }
+}
+
+impl std::fmt::Display for SerenityCommandOptionLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => value.fmt(f),
+            Self::Number(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Whether the name is a valid command or option name.
+///
+/// Discord allows 1 to 32 letters, digits, `-`, `_` and `'`, and letters with a lowercase variant must be lowercase.
+fn is_valid_name(name: &str) -> bool {
+    let length = name.chars().count();
+    (1..=32).contains(&length)
+        && name
+            .chars()
+            .all(|c| (c.is_alphanumeric() || matches!(c, '-' | '_' | '\'')) && !c.is_uppercase())
+}
+
+impl SerenityCommand {
+    /// Convert the command structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let command = SerenityCommand::builder()
+    ///   .name("ping")
+    ///   .description("Check whether the bot is alive")
+    ///   .build();
+    ///
+    /// let serenity_command = command.convert()?; // Result<CreateCommand, SerenityCommandConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityCommand using the builder
+    /// let command = SerenityCommand::builder()
+    ///   .name("ban")
+    ///   .description("Ban a member")
+    ///   .options(vec![
+    ///       SerenityCommandOption::builder()
+    ///           .kind(CommandOptionType::User)
+    ///           .name("member")
+    ///           .description("The member to ban")
+    ///           .required(true)
+    ///           .build(),
+    ///       SerenityCommandOption::builder()
+    ///           .kind(CommandOptionType::Integer)
+    ///           .name("days")
+    ///           .description("How many days of messages to delete")
+    ///           .min_value(0.0)
+    ///           .max_value(7.0)
+    ///           .build(),
+    ///   ])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateCommand
+    /// let serenity_command = command.convert()?; // Result<CreateCommand, SerenityCommandConvertError>
+    ///
+    /// // 3. Use the converted command in your bot
+    /// guild_id.create_command(&ctx.http, serenity_command).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityCommandConvertError::InvalidName]: The name is not a valid command name.
+    /// - [SerenityCommandConvertError::InvalidDescriptionLength]: The description is empty or exceeds 100 characters.
+    /// - [SerenityCommandConvertError::TooManyOptions]: More than 25 options are set.
+    /// - [SerenityCommandConvertError::DuplicateOptionName]: Two options have the same name.
+    /// - [SerenityCommandConvertError::RequiredAfterOptional]: A required option follows an optional one.
+    /// - [SerenityCommandConvertError::InvalidOptionName]: The name of an option is not a valid name.
+    /// - [SerenityCommandConvertError::InvalidOptionDescriptionLength]: The description of an option is empty or exceeds 100 characters.
+    /// - [SerenityCommandConvertError::UnsupportedOptionType]: An option is a subcommand, a subcommand group or of an unknown type.
+    /// - [SerenityCommandConvertError::InapplicableField]: A value is set that does not apply to the type of the option.
+    /// - [SerenityCommandConvertError::TooManyChoices]: More than 25 choices are set for an option.
+    /// - [SerenityCommandConvertError::InvalidChoiceNameLength]: The name of a choice is empty or exceeds 100 characters.
+    /// - [SerenityCommandConvertError::MismatchedChoice]: The value of a choice does not match the type of the option.
+    /// - [SerenityCommandConvertError::InvalidRange]: The minimum value of an option is larger than its maximum value.
+    /// - [SerenityCommandConvertError::NonIntegralValue]: The minimum or maximum value of an integer option is not an integer.
+    /// - [SerenityCommandConvertError::TooLargeLength]: The minimum or maximum length of a string option exceeds 6000.
+    /// - [SerenityCommandConvertError::InvalidLengthRange]: The minimum length of a string option is larger than its maximum length.
+    pub fn convert(&self) -> Result<CreateCommand, SerenityCommandConvertError> {
+        if !is_valid_name(&self.name) {
+            return Err(SerenityCommandConvertError::InvalidName {
+                actual: self.name.clone(),
+            });
+        }
+
+        let length = self.description.encode_utf16().count();
+        if !(1..=100).contains(&length) {
+            return Err(SerenityCommandConvertError::InvalidDescriptionLength {
+                actual: length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let mut command = CreateCommand::new(&self.name).description(&self.description);
+
+        if let Some(options) = &self.options {
+            if options.len() > 25 {
+                return Err(SerenityCommandConvertError::TooManyOptions {
+                    actual: options.len(),
+                    limit: 25,
+                });
+            }
+
+            let mut optional_seen = false;
+            for (index, option) in options.iter().enumerate() {
+                if options[..index]
+                    .iter()
+                    .any(|other| other.name == option.name)
+                {
+                    return Err(SerenityCommandConvertError::DuplicateOptionName {
+                        option: index,
+                        name: option.name.clone(),
+                    });
+                }
+                if option.required && optional_seen {
+                    return Err(SerenityCommandConvertError::RequiredAfterOptional {
+                        option: index,
+                    });
+                }
+                optional_seen |= !option.required;
+
+                command = command.add_option(convert_option(option, index)?);
+            }
+        }
+
+        Ok(command)
+    }
+}
+
+fn convert_option(
+    option: &SerenityCommandOption,
+    index: usize,
+) -> Result<CreateCommandOption, SerenityCommandConvertError> {
+    if !is_valid_name(&option.name) {
+        return Err(SerenityCommandConvertError::InvalidOptionName {
+            option: index,
+            actual: option.name.clone(),
+        });
+    }
+
+    let length = option.description.encode_utf16().count();
+    if !(1..=100).contains(&length) {
+        return Err(
+            SerenityCommandConvertError::InvalidOptionDescriptionLength {
+                option: index,
+                actual: length,
+                min: 1,
+                max: 100,
+            },
+        );
+    }
+
+    let kind = option.kind;
+    let is_string = kind == CommandOptionType::String;
+    let is_numeric = matches!(kind, CommandOptionType::Integer | CommandOptionType::Number);
+    if !matches!(
+        kind,
+        CommandOptionType::String
+            | CommandOptionType::Integer
+            | CommandOptionType::Number
+            | CommandOptionType::Boolean
+            | CommandOptionType::User
+            | CommandOptionType::Channel
+            | CommandOptionType::Role
+            | CommandOptionType::Mentionable
+            | CommandOptionType::Attachment
+    ) {
+        return Err(SerenityCommandConvertError::UnsupportedOptionType { option: index });
+    }
+
+    let inapplicable = |field| SerenityCommandConvertError::InapplicableField {
+        option: index,
+        field,
+    };
+
+    let mut created =
+        CreateCommandOption::new(kind, &option.name, &option.description).required(option.required);
+
+    if let Some(choices) = &option.choices {
+        if !is_string && !is_numeric {
+            return Err(inapplicable("choices"));
+        }
+        if choices.len() > 25 {
+            return Err(SerenityCommandConvertError::TooManyChoices {
+                option: index,
+                actual: choices.len(),
+                limit: 25,
+            });
+        }
+
+        for (choice_index, choice) in choices.iter().enumerate() {
+            created = convert_choice(created, kind, choice, (index, choice_index))?;
+        }
+    }
+
+    if option.min_value.is_some() || option.max_value.is_some() {
+        if !is_numeric {
+            let field = if option.min_value.is_some() {
+                "min_value"
+            } else {
+                "max_value"
+            };
+            return Err(inapplicable(field));
+        }
+        if let (Some(min), Some(max)) = (option.min_value, option.max_value)
+            && min.is_larger_than(max)
+        {
+            return Err(SerenityCommandConvertError::InvalidRange {
+                option: index,
+                min,
+                max,
+            });
+        }
+
+        let is_integer = kind == CommandOptionType::Integer;
+        for (field, value) in [
+            ("min_value", option.min_value),
+            ("max_value", option.max_value),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+            if !is_integer {
+                created = if field == "min_value" {
+                    created.min_number_value(value.as_f64())
+                } else {
+                    created.max_number_value(value.as_f64())
+                };
+                continue;
+            }
+
+            let integer = match value {
+                SerenityCommandOptionLimit::Integer(value) => value,
+                SerenityCommandOptionLimit::Number(value) if value.fract() == 0.0 => value as i64,
+                SerenityCommandOptionLimit::Number(value) => {
+                    return Err(SerenityCommandConvertError::NonIntegralValue {
+                        option: index,
+                        field,
+                        actual: value,
+                    });
+                }
+            };
+            // Serenity only accepts non-negative integer limits.
+            let Ok(integer) = u64::try_from(integer) else {
+                return Err(SerenityCommandConvertError::NegativeIntegerValue {
+                    option: index,
+                    field,
+                    actual: integer,
+                });
+            };
+            created = if field == "min_value" {
+                created.min_int_value(integer)
+            } else {
+                created.max_int_value(integer)
+            };
+        }
+    }
+
+    if option.min_length.is_some() || option.max_length.is_some() {
+        if !is_string {
+            let field = if option.min_length.is_some() {
+                "min_length"
+            } else {
+                "max_length"
+            };
+            return Err(inapplicable(field));
+        }
+
+        for (field, value) in [
+            ("min_length", option.min_length),
+            ("max_length", option.max_length),
+        ] {
+            if let Some(value) = value
+                && value > 6000
+            {
+                return Err(SerenityCommandConvertError::TooLargeLength {
+                    option: index,
+                    field,
+                    actual: value,
+                    limit: 6000,
+                });
+            }
+        }
+        if let (Some(min), Some(max)) = (option.min_length, option.max_length)
+            && min > max
+        {
+            return Err(SerenityCommandConvertError::InvalidLengthRange {
+                option: index,
+                min,
+                max,
+            });
+        }
+
+        if let Some(min) = option.min_length {
+            created = created.min_length(min);
+        }
+        if let Some(max) = option.max_length {
+            created = created.max_length(max);
+        }
+    }
+
+    Ok(created)
+}
+
+fn convert_choice(
+    created: CreateCommandOption,
+    kind: CommandOptionType,
+    choice: &SerenityCommandChoice,
+    (option, index): (usize, usize),
+) -> Result<CreateCommandOption, SerenityCommandConvertError> {
+    let length = choice.name.encode_utf16().count();
+    if !(1..=100).contains(&length) {
+        return Err(SerenityCommandConvertError::InvalidChoiceNameLength {
+            option,
+            choice: index,
+            actual: length,
+            min: 1,
+            max: 100,
+        });
+    }
+
+    match (kind, &choice.value) {
+        (CommandOptionType::String, SerenityCommandChoiceValue::String(value)) => {
+            Ok(created.add_string_choice(&choice.name, value))
+        }
+        (CommandOptionType::Integer, SerenityCommandChoiceValue::Integer(value)) => {
+            Ok(created.add_int_choice(&choice.name, *value))
+        }
+        (CommandOptionType::Number, SerenityCommandChoiceValue::Number(value)) => {
+            Ok(created.add_number_choice(&choice.name, *value))
+        }
+        _ => Err(SerenityCommandConvertError::MismatchedChoice {
+            option,
+            choice: index,
+        }),
+    }
+}
+
+impl_convert!(SerenityCommand => CreateCommand, SerenityCommandConvertError);
+
+/// A type that can be used as the value of a slash command option.
+///
+/// This is implemented for the types Discord can send, and by `#[derive(SerenityCommandChoice)]` for enums whose variants are the choices of a string option.
+pub trait SerenityCommandOptionValue: Sized {
+    /// The type of the option.
+    const KIND: CommandOptionType;
+
+    /// The predefined values the user can pick from, if any.
+    fn choices() -> Option<Vec<SerenityCommandChoice>> {
+        None
+    }
+
+    /// Read the value sent by Discord. Returns `None` if the value is not valid for this type.
+    fn from_value(value: &CommandDataOptionValue) -> Option<Self>;
+}
+
+macro_rules! impl_option_value {
+    ($type:ty, $kind:ident, $getter:ident) => {
+        impl SerenityCommandOptionValue for $type {
+            const KIND: CommandOptionType = CommandOptionType::$kind;
+
+            fn from_value(value: &CommandDataOptionValue) -> Option<Self> {
+                value.$getter().map(Into::into)
+            }
+        }
+    };
+}
+
+impl_option_value!(String, String, as_str);
+impl_option_value!(i64, Integer, as_i64);
+impl_option_value!(f64, Number, as_f64);
+impl_option_value!(bool, Boolean, as_bool);
+impl_option_value!(UserId, User, as_user_id);
+impl_option_value!(ChannelId, Channel, as_channel_id);
+impl_option_value!(RoleId, Role, as_role_id);
+impl_option_value!(GenericId, Mentionable, as_mentionable);
+impl_option_value!(AttachmentId, Attachment, as_attachment_id);
+
+/// A slash command whose definition and parsed options are described by one struct.
+///
+/// Implement it with `#[derive(SerenityCommand)]` (requires the `derive` feature):
+///
+/// ```rs
+/// #[derive(SerenityCommand)]
+/// #[command(name = "ban", description = "Ban a member")]
+/// struct Ban {
+///     #[command(description = "The member to ban")]
+///     member: UserId,
+///     #[command(description = "How many days of messages to delete", min = 0, max = 7)]
+///     days: Option<i64>,
+/// }
+///
+/// // Register the command
+/// guild_id.create_command(&ctx.http, Ban::command().convert()?).await?;
+///
+/// // Handle the interaction
+/// let ban = Ban::parse_interaction(&interaction)?;
+/// ```
+pub trait SerenityCommandDefinition: Sized {
+    /// The definition of the command, ready to be converted and registered.
+    fn command() -> SerenityCommand;
+
+    /// Read the options of an invoked command.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a [SerenityCommandParseError] if the data belongs to another command, or if an option is missing or has an unexpected value.
+    fn parse(data: &CommandData) -> Result<Self, SerenityCommandParseError>;
+
+    /// Read the options of the command invoked by the interaction.
+    ///
+    /// # Errors
+    ///
+    /// See [SerenityCommandDefinition::parse].
+    fn parse_interaction(
+        interaction: &CommandInteraction,
+    ) -> Result<Self, SerenityCommandParseError> {
+        Self::parse(&interaction.data)
+    }
+}
+
+/// Errors that can occur when reading the options of an invoked command.
+///
+/// Discord validates the options against the registered command, so these errors usually mean that the registered command is outdated.
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityCommandParseError {
+    /// This occurs when the data belongs to another command.
+    #[error("Expected the command {expected:?}, but received {actual:?}.")]
+    UnexpectedCommand { expected: String, actual: String },
+    /// This occurs when a required option was not sent.
+    #[error("The option {name:?} is missing.")]
+    MissingOption { name: String },
+    /// This occurs when an option was sent with another type than expected.
+    #[error("The option {name:?} must be {}, but is {}.", option_type_name(*.expected), option_type_name(*.actual))]
+    MismatchedOption {
+        name: String,
+        expected: CommandOptionType,
+        actual: CommandOptionType,
+    },
+    /// This occurs when the value of an option cannot be read, e.g. a string that is none of the choices.
+    #[error("The option {name:?} has an invalid value: {actual}.")]
+    InvalidValue { name: String, actual: String },
+}
+
+fn option_type_name(kind: CommandOptionType) -> &'static str {
+    match kind {
+        CommandOptionType::String => "a string",
+        CommandOptionType::Integer => "an integer",
+        CommandOptionType::Number => "a number",
+        CommandOptionType::Boolean => "a boolean",
+        CommandOptionType::User => "a user",
+        CommandOptionType::Channel => "a channel",
+        CommandOptionType::Role => "a role",
+        CommandOptionType::Mentionable => "a mentionable",
+        CommandOptionType::Attachment => "an attachment",
+        CommandOptionType::SubCommand => "a subcommand",
+        CommandOptionType::SubCommandGroup => "a subcommand group",
+        _ => "an unknown type",
+    }
+}
+
+/// Read an option that must have been sent.
+///
+/// # Errors
+///
+/// This function returns [SerenityCommandParseError::MissingOption] if the option was not sent, and the errors of [optional_option] otherwise.
+pub fn required_option<T: SerenityCommandOptionValue>(
+    options: &[CommandDataOption],
+    name: &str,
+) -> Result<T, SerenityCommandParseError> {
+    optional_option(options, name)?.ok_or_else(|| SerenityCommandParseError::MissingOption {
+        name: name.to_string(),
+    })
+}
+
+/// Read an option that may have been left out.
+///
+/// # Errors
+///
+/// This function may return the following error:
+///
+/// - [SerenityCommandParseError::MismatchedOption]: The option was sent with another type.
+/// - [SerenityCommandParseError::InvalidValue]: The value cannot be read as `T`.
+pub fn optional_option<T: SerenityCommandOptionValue>(
+    options: &[CommandDataOption],
+    name: &str,
+) -> Result<Option<T>, SerenityCommandParseError> {
+    let Some(option) = options.iter().find(|option| option.name == name) else {
+        return Ok(None);
+    };
+
+    let actual = option.value.kind();
+    if actual != T::KIND {
+        return Err(SerenityCommandParseError::MismatchedOption {
+            name: name.to_string(),
+            expected: T::KIND,
+            actual,
+        });
+    }
+
+    T::from_value(&option.value)
+        .map(Some)
+        .ok_or_else(|| SerenityCommandParseError::InvalidValue {
+            name: name.to_string(),
+            actual: match &option.value {
+                CommandDataOptionValue::String(value) => value.clone(),
+                CommandDataOptionValue::Integer(value) => value.to_string(),
+                CommandDataOptionValue::Number(value) => value.to_string(),
+                value => format!("{value:?}"),
+            },
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::json::json;
+
+    use super::*;
+
+    static MOCK_NAME: &str = "ban";
+    static MOCK_TEXT: &str = "This is a test text.";
+
+    fn mock_option(kind: CommandOptionType, name: &str) -> SerenityCommandOption {
+        SerenityCommandOption::builder()
+            .kind(kind)
+            .name(name)
+            .description(MOCK_TEXT)
+            .build()
+    }
+
+    fn mock_data(options: serenity::json::Value) -> CommandData {
+        serde_json::from_value(json!({
+            "id": "1",
+            "name": MOCK_NAME,
+            "type": 1,
+            "options": options,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_command_conversion() {
+        // serenity-builder
+        let mock_command = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![
+                SerenityCommandOption::builder()
+                    .kind(CommandOptionType::User)
+                    .name("member")
+                    .description(MOCK_TEXT)
+                    .required(true)
+                    .build(),
+                SerenityCommandOption::builder()
+                    .kind(CommandOptionType::Integer)
+                    .name("days")
+                    .description(MOCK_TEXT)
+                    .min_value(0.0)
+                    .max_value(7.0)
+                    .build(),
+                SerenityCommandOption::builder()
+                    .kind(CommandOptionType::String)
+                    .name("mode")
+                    .description(MOCK_TEXT)
+                    .choices(vec![
+                        SerenityCommandChoice::builder()
+                            .name("Soft")
+                            .value("soft")
+                            .build(),
+                    ])
+                    .max_length(10_u16)
+                    .build(),
+            ])
+            .build();
+        // serenity
+        let serenity_command = CreateCommand::new(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::User, "member", MOCK_TEXT)
+                    .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "days", MOCK_TEXT)
+                    .min_int_value(0)
+                    .max_int_value(7),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::String, "mode", MOCK_TEXT)
+                    .add_string_choice("Soft", "soft")
+                    .max_length(10),
+            );
+
+        let converted = mock_command.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_command).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_command_conversion_invalid_name() {
+        let mock_command = SerenityCommand::builder()
+            .name("Ban")
+            .description(MOCK_TEXT)
+            .build();
+
+        let converted = mock_command.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityCommandConvertError::InvalidName { .. })
+        ));
+    }
+
+    #[test]
+    fn test_command_conversion_required_after_optional() {
+        let mut required = mock_option(CommandOptionType::User, "member");
+        required.required = true;
+        let mock_command = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![
+                mock_option(CommandOptionType::String, "reason"),
+                required,
+            ])
+            .build();
+
+        let error = mock_command.convert().err().unwrap();
+        assert!(matches!(
+            error,
+            SerenityCommandConvertError::RequiredAfterOptional { option: 1 }
+        ));
+        assert_eq!(error.field_path(), "options[1].required");
+    }
+
+    #[test]
+    fn test_command_conversion_mismatched_choice() {
+        let mut option = mock_option(CommandOptionType::Integer, "days");
+        option.choices = Some(vec![
+            SerenityCommandChoice::builder()
+                .name("One")
+                .value("1")
+                .build(),
+        ]);
+        let mock_command = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![option])
+            .build();
+
+        let error = mock_command.convert().err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Inapplicable);
+        assert_eq!(error.field_path(), "options[0].choices[0].value");
+    }
+
+    #[test]
+    fn test_command_conversion_inapplicable_field() {
+        let mut option = mock_option(CommandOptionType::Integer, "days");
+        option.max_length = Some(10);
+        let mock_command = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![option])
+            .build();
+
+        let converted = mock_command.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityCommandConvertError::InapplicableField {
+                option: 0,
+                field: "max_length"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_command_conversion_integer_limits() {
+        let mut exact = mock_option(CommandOptionType::Integer, "id");
+        exact.min_value = Some(SerenityCommandOptionLimit::Integer(9007199254740993));
+        let mut negative = mock_option(CommandOptionType::Integer, "offset");
+        negative.min_value = Some((-5).into());
+        let mut number = mock_option(CommandOptionType::Number, "ratio");
+        number.min_value = Some((-5).into());
+
+        let converted = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![exact, number])
+            .build()
+            .convert()
+            .unwrap();
+        let value = serde_json::to_value(converted).unwrap();
+        assert_eq!(
+            value["options"][0]["min_value"],
+            json!(9007199254740993_u64)
+        );
+        assert_eq!(value["options"][1]["min_value"], json!(-5.0));
+
+        let error = SerenityCommand::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .options(vec![negative])
+            .build()
+            .convert()
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            SerenityCommandConvertError::NegativeIntegerValue {
+                option: 0,
+                field: "min_value",
+                actual: -5
+            }
+        ));
+        assert_eq!(error.kind(), ErrorKind::Range);
+        assert_eq!(error.field_path(), "options[0].min_value");
+    }
+
+    #[test]
+    fn test_command_parse_options() {
+        let data = mock_data(json!([
+            { "name": "member", "type": 6, "value": "123" },
+            { "name": "days", "type": 4, "value": 3 },
+        ]));
+
+        let member: UserId = required_option(&data.options, "member").unwrap();
+        let days: Option<i64> = optional_option(&data.options, "days").unwrap();
+        let reason: Option<String> = optional_option(&data.options, "reason").unwrap();
+
+        assert_eq!(member, UserId::new(123));
+        assert_eq!(days, Some(3));
+        assert_eq!(reason, None);
+    }
+
+    #[test]
+    fn test_command_parse_errors() {
+        let data = mock_data(json!([{ "name": "days", "type": 3, "value": "three" }]));
+
+        let missing = required_option::<UserId>(&data.options, "member").unwrap_err();
+        assert_eq!(missing.to_string(), "The option \"member\" is missing.");
+
+        let mismatched = required_option::<i64>(&data.options, "days").unwrap_err();
+        assert_eq!(
+            mismatched.to_string(),
+            "The option \"days\" must be an integer, but is a string."
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_command_derive() {
+        #[derive(crate::SerenityCommandChoice, Debug, PartialEq)]
+        enum Mode {
+            #[choice(name = "Soft ban", value = "soft")]
+            Soft,
+            Hard,
+        }
+
+        #[derive(crate::SerenityCommand)]
+        #[command(name = "ban", description = "Ban a member")]
+        struct Ban {
+            #[command(description = "The member to ban")]
+            member: UserId,
+            #[command(description = "How many days of messages to delete", min = 0, max = 7)]
+            days: Option<i64>,
+            #[command(description = "How to ban the member")]
+            mode: Option<Mode>,
+        }
+
+        // serenity-builder
+        let mock_command = Ban::command().convert();
+        // serenity
+        let serenity_command = CreateCommand::new(MOCK_NAME)
+            .description("Ban a member")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::User, "member", "The member to ban")
+                    .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "days",
+                    "How many days of messages to delete",
+                )
+                .min_int_value(0)
+                .max_int_value(7),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "mode",
+                    "How to ban the member",
+                )
+                .add_string_choice("Soft ban", "soft")
+                .add_string_choice("Hard", "Hard"),
+            );
+
+        assert!(mock_command.is_ok());
+        assert_eq!(
+            serde_json::to_value(mock_command.unwrap()).unwrap(),
+            serde_json::to_value(serenity_command).unwrap()
+        );
+
+        let ban = Ban::parse(&mock_data(json!([
+            { "name": "member", "type": 6, "value": "123" },
+            { "name": "mode", "type": 3, "value": "soft" },
+        ])))
+        .unwrap();
+        assert_eq!(ban.member, UserId::new(123));
+        assert_eq!(ban.days, None);
+        assert_eq!(ban.mode, Some(Mode::Soft));
+
+        let invalid = Ban::parse(&mock_data(json!([
+            { "name": "member", "type": 6, "value": "123" },
+            { "name": "mode", "type": 3, "value": "permanent" },
+        ])));
+        assert!(matches!(
+            invalid,
+            Err(SerenityCommandParseError::InvalidValue { .. })
+        ));
+    }
+}
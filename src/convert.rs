@@ -0,0 +1,111 @@
+use crate::error::SerenityBuilderError;
+
+/// A common interface for every model that can be converted into a Serenity builder.
+///
+/// Each model also has an inherent `convert()` method, so this trait is only needed when writing generic code.
+///
+/// ```rs
+/// fn convert_all<T: SerenityConvert>(models: &[T]) -> Result<Vec<T::Output>, SerenityBuilderError> {
+///     models
+///         .iter()
+///         .map(|model| model.convert().map_err(Into::into))
+///         .collect()
+/// }
+/// ```
+///
+/// The conversion is also available as [TryFrom], e.g. `CreateEmbed::try_from(&embed)`.
+pub trait SerenityConvert {
+    /// The Serenity builder (or request body) produced by the conversion.
+    type Output;
+    /// The error returned when the model does not satisfy the Discord API limitations.
+    type Error: std::error::Error + Into<SerenityBuilderError>;
+
+    /// Convert the model into a value usable in Serenity.
+    fn convert(&self) -> Result<Self::Output, Self::Error>;
+}
+
+/// Implement [SerenityConvert] and [TryFrom] for a model by delegating to its inherent `convert()` method.
+// Unused when every builder feature is disabled.
+#[allow(unused_macros)]
+macro_rules! impl_convert {
+    ($model:ty => $output:ty, $error:ty) => {
+        impl $crate::convert::SerenityConvert for $model {
+            type Output = $output;
+            type Error = $error;
+
+            fn convert(&self) -> Result<Self::Output, Self::Error> {
+                <$model>::convert(self)
+            }
+        }
+
+        impl TryFrom<&$model> for $output {
+            type Error = $error;
+
+            fn try_from(value: &$model) -> Result<Self, Self::Error> {
+                value.convert()
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_convert;
+
+#[cfg(all(test, feature = "embed"))]
+mod tests {
+    use serenity::all::CreateEmbed;
+
+    use super::*;
+    use crate::model::embed::SerenityEmbed;
+
+    fn convert_all<T: SerenityConvert>(
+        models: &[T],
+    ) -> Result<Vec<T::Output>, SerenityBuilderError> {
+        models
+            .iter()
+            .map(|model| model.convert().map_err(Into::into))
+            .collect()
+    }
+
+    #[test]
+    fn test_generic_conversion() {
+        let embeds = vec![
+            SerenityEmbed::builder().title("First").build(),
+            SerenityEmbed::builder().title("Second").build(),
+        ];
+
+        let converted = convert_all(&embeds);
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            converted.unwrap(),
+            vec![
+                CreateEmbed::default().title("First"),
+                CreateEmbed::default().title("Second"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generic_conversion_error() {
+        let embeds = vec![
+            SerenityEmbed::builder().title("First").build(),
+            SerenityEmbed::builder()
+                .description("a".repeat(5000))
+                .build(),
+        ];
+
+        let converted = convert_all(&embeds);
+        assert!(matches!(converted, Err(SerenityBuilderError::Embed(_))));
+    }
+
+    #[test]
+    fn test_try_from_conversion() {
+        let embed = SerenityEmbed::builder().title("First").build();
+
+        let converted = CreateEmbed::try_from(&embed);
+
+        assert!(converted.is_ok());
+        assert_eq!(converted.unwrap(), CreateEmbed::default().title("First"));
+    }
+}
@@ -0,0 +1,492 @@
+use std::fmt;
+
+use crate::model::embed::{SerenityEmbed, SerenityEmbedField};
+use crate::model::message::{SerenityMessage, SerenityMessageMentionType};
+
+/// A single difference between two embeds, found by [SerenityEmbed::diff].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SerenityEmbedChange {
+    /// A value was set, cleared or changed. Values of fields are included, e.g. `fields[2].value`.
+    Changed {
+        path: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// A field was added at the index.
+    FieldAdded {
+        index: usize,
+        field: SerenityEmbedField,
+    },
+    /// A field was removed from the index.
+    FieldRemoved {
+        index: usize,
+        field: SerenityEmbedField,
+    },
+}
+
+impl SerenityEmbedChange {
+    /// The path of the changed value within the embed, e.g. `title` or `fields[2].value`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::Changed { path, .. } => path.clone(),
+            Self::FieldAdded { index, .. } | Self::FieldRemoved { index, .. } => {
+                format!("fields[{index}]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SerenityEmbedChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Changed { path, old, new } => {
+                write!(f, "{path} changed from {} to {}", Shown(old), Shown(new))
+            }
+            Self::FieldAdded { index, field } => {
+                write!(f, "fields[{index}] added: {:?}", field.name)
+            }
+            Self::FieldRemoved { index, field } => {
+                write!(f, "fields[{index}] removed: {:?}", field.name)
+            }
+        }
+    }
+}
+
+/// A single difference between two messages, found by [SerenityMessage::diff].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SerenityMessageChange {
+    /// A value of the message was set, cleared or changed, e.g. `content`.
+    Changed {
+        path: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// An embed that is in both messages changed.
+    Embed {
+        index: usize,
+        change: SerenityEmbedChange,
+    },
+    /// An embed was added at the index.
+    EmbedAdded {
+        index: usize,
+        embed: Box<SerenityEmbed>,
+    },
+    /// An embed was removed from the index.
+    EmbedRemoved {
+        index: usize,
+        embed: Box<SerenityEmbed>,
+    },
+}
+
+impl SerenityMessageChange {
+    /// The path of the changed value within the message, e.g. `content` or `embeds[0].title`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::Changed { path, .. } => path.clone(),
+            Self::Embed { index, change } => format!("embeds[{index}].{}", change.field_path()),
+            Self::EmbedAdded { index, .. } | Self::EmbedRemoved { index, .. } => {
+                format!("embeds[{index}]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SerenityMessageChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Changed { path, old, new } => {
+                write!(f, "{path} changed from {} to {}", Shown(old), Shown(new))
+            }
+            Self::Embed { index, change } => write!(f, "embeds[{index}].{change}"),
+            Self::EmbedAdded { index, .. } => write!(f, "embeds[{index}] added"),
+            Self::EmbedRemoved { index, .. } => write!(f, "embeds[{index}] removed"),
+        }
+    }
+}
+
+/// Shows a value of a change, quoted, or `(unset)`.
+struct Shown<'a>(&'a Option<String>);
+
+impl fmt::Display for Shown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => write!(f, "{value:?}"),
+            None => f.write_str("(unset)"),
+        }
+    }
+}
+
+/// Collects the changed values as `(path, old, new)`.
+#[derive(Default)]
+struct Changes(Vec<(String, Option<String>, Option<String>)>);
+
+impl Changes {
+    fn compare<T: PartialEq>(
+        &mut self,
+        path: impl Into<String>,
+        old: Option<&T>,
+        new: Option<&T>,
+        show: impl Fn(&T) -> String,
+    ) {
+        if old != new {
+            self.0.push((path.into(), old.map(&show), new.map(&show)));
+        }
+    }
+}
+
+impl SerenityEmbed {
+    /// List the differences from this embed to `new`, e.g. to decide whether an edit is needed and to log what changed.
+    ///
+    /// Fields are compared by their position, so a field inserted in the middle shows up as changes to the following fields.
+    /// An unset list of fields and an empty one are different values, so this shows up as a change of `fields`.
+    /// An empty list means the embeds are equal.
+    ///
+    /// ```rs
+    /// let changes = current.diff(&next);
+    /// if !changes.is_empty() {
+    ///     for change in &changes {
+    ///         tracing::info!("{change}"); // e.g. `title changed from "Offline" to "Online"`
+    ///     }
+    ///     message.edit(&ctx.http, EditMessage::new().embed(next.convert()?)).await?;
+    /// }
+    /// ```
+    pub fn diff(&self, new: &SerenityEmbed) -> Vec<SerenityEmbedChange> {
+        let mut changes = Changes::default();
+        changes.compare(
+            "title",
+            self.title.as_ref(),
+            new.title.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "description",
+            self.description.as_ref(),
+            new.description.as_ref(),
+            String::clone,
+        );
+        changes.compare("url", self.url.as_ref(), new.url.as_ref(), String::clone);
+        changes.compare(
+            "timestamp",
+            self.timestamp.as_ref(),
+            new.timestamp.as_ref(),
+            ToString::to_string,
+        );
+        changes.compare(
+            "color",
+            self.color.as_ref(),
+            new.color.as_ref(),
+            ToString::to_string,
+        );
+        changes.compare(
+            "footer_text",
+            self.footer_text.as_ref(),
+            new.footer_text.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "footer_icon_url",
+            self.footer_icon_url.as_ref(),
+            new.footer_icon_url.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "image_url",
+            self.image_url.as_ref(),
+            new.image_url.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "thumbnail_url",
+            self.thumbnail_url.as_ref(),
+            new.thumbnail_url.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "author_name",
+            self.author_name.as_ref(),
+            new.author_name.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "author_url",
+            self.author_url.as_ref(),
+            new.author_url.as_ref(),
+            String::clone,
+        );
+        changes.compare(
+            "author_icon_url",
+            self.author_icon_url.as_ref(),
+            new.author_icon_url.as_ref(),
+            String::clone,
+        );
+
+        let old_fields = self.fields.as_deref().unwrap_or_default();
+        let new_fields = new.fields.as_deref().unwrap_or_default();
+        if old_fields.is_empty() && new_fields.is_empty() {
+            changes.compare("fields", self.fields.as_ref(), new.fields.as_ref(), |_| {
+                "[]".to_string()
+            });
+        }
+        for (index, (old, new)) in old_fields.iter().zip(new_fields).enumerate() {
+            changes.compare(
+                format!("fields[{index}].name"),
+                Some(&old.name),
+                Some(&new.name),
+                String::clone,
+            );
+            changes.compare(
+                format!("fields[{index}].value"),
+                Some(&old.value),
+                Some(&new.value),
+                String::clone,
+            );
+            changes.compare(
+                format!("fields[{index}].inline"),
+                Some(&old.inline),
+                Some(&new.inline),
+                ToString::to_string,
+            );
+        }
+
+        let mut diff: Vec<_> = changes
+            .0
+            .into_iter()
+            .map(|(path, old, new)| SerenityEmbedChange::Changed { path, old, new })
+            .collect();
+        diff.extend(
+            new_fields
+                .iter()
+                .enumerate()
+                .skip(old_fields.len())
+                .map(|(index, field)| SerenityEmbedChange::FieldAdded {
+                    index,
+                    field: field.clone(),
+                }),
+        );
+        diff.extend(
+            old_fields
+                .iter()
+                .enumerate()
+                .skip(new_fields.len())
+                .map(|(index, field)| SerenityEmbedChange::FieldRemoved {
+                    index,
+                    field: field.clone(),
+                }),
+        );
+        diff
+    }
+}
+
+impl SerenityMessage {
+    /// List the differences from this message to `new`, e.g. to decide whether an edit is needed and to log what changed.
+    ///
+    /// Embeds are compared by their position, like the fields in [SerenityEmbed::diff].
+    /// An unset list of embeds and an empty one are different values, so this shows up as a change of `embeds`.
+    /// An empty list means the messages are equal.
+    pub fn diff(&self, new: &SerenityMessage) -> Vec<SerenityMessageChange> {
+        let mut changes = Changes::default();
+        changes.compare(
+            "content",
+            self.content.as_ref(),
+            new.content.as_ref(),
+            String::clone,
+        );
+        changes.compare("tts", Some(&self.tts), Some(&new.tts), ToString::to_string);
+        changes.compare(
+            "mention_type",
+            self.mention_type.as_ref(),
+            new.mention_type.as_ref(),
+            show_mention_type,
+        );
+        changes.compare(
+            "sticker_ids",
+            self.sticker_ids.as_ref(),
+            new.sticker_ids.as_ref(),
+            |ids| show_ids(ids),
+        );
+
+        let old_embeds = self.embeds.as_deref().unwrap_or_default();
+        let new_embeds = new.embeds.as_deref().unwrap_or_default();
+        if old_embeds.is_empty() && new_embeds.is_empty() {
+            changes.compare("embeds", self.embeds.as_ref(), new.embeds.as_ref(), |_| {
+                "[]".to_string()
+            });
+        }
+
+        let mut diff: Vec<_> = changes
+            .0
+            .into_iter()
+            .map(|(path, old, new)| SerenityMessageChange::Changed { path, old, new })
+            .collect();
+
+        for (index, (old, new)) in old_embeds.iter().zip(new_embeds).enumerate() {
+            diff.extend(
+                old.diff(new)
+                    .into_iter()
+                    .map(|change| SerenityMessageChange::Embed { index, change }),
+            );
+        }
+        diff.extend(
+            new_embeds
+                .iter()
+                .enumerate()
+                .skip(old_embeds.len())
+                .map(|(index, embed)| SerenityMessageChange::EmbedAdded {
+                    index,
+                    embed: Box::new(embed.clone()),
+                }),
+        );
+        diff.extend(
+            old_embeds
+                .iter()
+                .enumerate()
+                .skip(new_embeds.len())
+                .map(|(index, embed)| SerenityMessageChange::EmbedRemoved {
+                    index,
+                    embed: Box::new(embed.clone()),
+                }),
+        );
+        diff
+    }
+}
+
+fn show_mention_type(mention_type: &SerenityMessageMentionType) -> String {
+    match mention_type {
+        SerenityMessageMentionType::Everyone => "everyone".to_string(),
+        SerenityMessageMentionType::Here => "here".to_string(),
+        SerenityMessageMentionType::Users(user_ids) => format!("users {}", show_ids(user_ids)),
+        SerenityMessageMentionType::Roles(role_ids) => format!("roles {}", show_ids(role_ids)),
+        SerenityMessageMentionType::Reply(message) => format!("reply to {}", message.id),
+    }
+}
+
+fn show_ids<T: fmt::Display>(ids: &[T]) -> String {
+    let ids: Vec<_> = ids.iter().map(ToString::to_string).collect();
+    format!("[{}]", ids.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::StickerId;
+
+    use super::*;
+    use crate::model::color::SerenityColor;
+
+    static MOCK_TEXT: &str = "This is a test text.";
+
+    fn field(name: &str, value: &str) -> SerenityEmbedField {
+        SerenityEmbedField::builder()
+            .name(name)
+            .value(value)
+            .build()
+    }
+
+    fn mock_embed() -> SerenityEmbed {
+        SerenityEmbed::builder()
+            .title("Offline")
+            .description(MOCK_TEXT)
+            .color(SerenityColor::RED)
+            .fields(vec![field("Players", "0"), field("Region", "Tokyo")])
+            .build()
+    }
+
+    #[test]
+    fn test_embed_diff_equal() {
+        assert!(mock_embed().diff(&mock_embed()).is_empty());
+        assert_eq!(mock_embed(), mock_embed());
+    }
+
+    #[test]
+    fn test_embed_diff() {
+        let mut new = mock_embed();
+        new.title = Some("Online".to_string());
+        new.description = None;
+        new.color = Some(SerenityColor::GREEN);
+        new.fields = Some(vec![
+            field("Players", "12"),
+            field("Region", "Tokyo"),
+            field("Uptime", "1h"),
+        ]);
+
+        let changes = mock_embed().diff(&new);
+        let shown: Vec<_> = changes.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            shown,
+            [
+                r#"title changed from "Offline" to "Online""#,
+                r#"description changed from "This is a test text." to (unset)"#,
+                r##"color changed from "#ED4245" to "#57F287""##,
+                r#"fields[0].value changed from "0" to "12""#,
+                r#"fields[2] added: "Uptime""#,
+            ]
+        );
+        assert_eq!(changes[3].field_path(), "fields[0].value");
+        assert_eq!(changes[4].field_path(), "fields[2]");
+
+        let removed = new.diff(&mock_embed());
+        assert!(matches!(
+            removed.last(),
+            Some(SerenityEmbedChange::FieldRemoved { index: 2, field }) if field.name == "Uptime"
+        ));
+    }
+
+    #[test]
+    fn test_diff_unset_and_empty_lists() {
+        let unset = SerenityEmbed::default();
+        let empty = SerenityEmbed::builder().fields(vec![]).build();
+
+        let changes = unset.diff(&empty);
+        assert_ne!(unset, empty);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field_path(), "fields");
+        assert_eq!(
+            changes[0].to_string(),
+            r#"fields changed from (unset) to "[]""#
+        );
+
+        let unset = SerenityMessage::builder().content(MOCK_TEXT).build();
+        let empty = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![])
+            .build();
+
+        let changes = empty.diff(&unset);
+        assert_ne!(empty, unset);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field_path(), "embeds");
+        assert_eq!(
+            changes[0].to_string(),
+            r#"embeds changed from "[]" to (unset)"#
+        );
+    }
+
+    #[test]
+    fn test_message_diff() {
+        let old = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![mock_embed()])
+            .build();
+        let mut new_embed = mock_embed();
+        new_embed.title = Some("Online".to_string());
+        let new = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![new_embed, mock_embed()])
+            .sticker_ids(vec![StickerId::new(1)])
+            .build();
+
+        let changes = old.diff(&new);
+        let paths: Vec<_> = changes.iter().map(|change| change.field_path()).collect();
+
+        assert_eq!(paths, ["sticker_ids", "embeds[0].title", "embeds[1]"]);
+        assert_eq!(
+            changes[0].to_string(),
+            r#"sticker_ids changed from (unset) to "[1]""#
+        );
+        assert_eq!(
+            changes[1].to_string(),
+            r#"embeds[0].title changed from "Offline" to "Online""#
+        );
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+}
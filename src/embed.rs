@@ -1,5 +1,8 @@
 use serenity::all::{Colour, CreateEmbed};
 
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::color::SerenityColor;
 use crate::model::embed::SerenityEmbed;
 
 /// Errors that can occur when converting a [SerenityEmbed] to a [serenity::all::CreateEmbed].
@@ -11,16 +14,45 @@ pub enum SerenityEmbedConvertError {
      * Serenity-builder will report an error during conversion.
      * Serenity does not return an error and leaves the response entirely up to the Discord API.
      */
-    #[error("The description exceeds the maximum length of 4096 characters.")]
-    TooLongDescription,
+    #[error("The description is too long: {actual} characters (limit: {limit}).")]
+    TooLongDescription { actual: usize, limit: usize },
     /**
      * This occurs when the number of embedded fields exceeds 25 and hits the Discord API limit.
      *
      * Serenity-builder will report an error during conversion.
      * Serenity does not return an error and leaves the response entirely up to the Discord API.
      */
-    #[error("The number of fields exceeds the maximum of 25.")]
-    TooManyFields,
+    #[error("There are too many fields: {actual} fields (limit: {limit}).")]
+    TooManyFields { actual: usize, limit: usize },
+    /**
+     * This occurs when the color code does not fit in 24 bits, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The color is out of range: {actual:#X} (limit: {limit:#X}).")]
+    InvalidColor { actual: u32, limit: u32 },
+}
+
+impl SerenityEmbedConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongDescription { .. } => ErrorKind::Length,
+            Self::TooManyFields { .. } => ErrorKind::Count,
+            Self::InvalidColor { .. } => ErrorKind::Range,
+        }
+    }
+
+    /// The path of the offending value within the embed.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongDescription { .. } => "description",
+            Self::TooManyFields { .. } => "fields",
+            Self::InvalidColor { .. } => "color",
+        }
+        .to_string()
+    }
 }
 
 impl SerenityEmbed {
@@ -59,6 +91,7 @@ impl SerenityEmbed {
     ///
     /// - [`SerenityEmbedConvertError::TooLongDescription`]: The description exceeds the maximum length of 4096 characters.
     /// - [`SerenityEmbedConvertError::TooManyFields`]: The number of fields exceeds the maximum of 25.
+    /// - [`SerenityEmbedConvertError::InvalidColor`]: The color code does not fit in 24 bits.
     pub fn convert(&self) -> Result<CreateEmbed, SerenityEmbedConvertError> {
         let mut embed = serenity::builder::CreateEmbed::default();
 
@@ -67,8 +100,13 @@ impl SerenityEmbed {
         }
 
         if let Some(description) = &self.description {
-            if description.len() > 4096 {
-                return Err(SerenityEmbedConvertError::TooLongDescription);
+            // Internal string data in the Discord API is handled in UTF-16 code units.
+            let length = description.encode_utf16().count();
+            if length > 4096 {
+                return Err(SerenityEmbedConvertError::TooLongDescription {
+                    actual: length,
+                    limit: 4096,
+                });
             }
 
             embed = embed.description(description);
@@ -82,8 +120,14 @@ impl SerenityEmbed {
             embed = embed.timestamp(timestamp);
         }
 
-        if let Some(color) = &self.color {
-            embed = embed.color(Colour(*color));
+        if let Some(color) = self.color {
+            if !color.is_valid() {
+                return Err(SerenityEmbedConvertError::InvalidColor {
+                    actual: color.value(),
+                    limit: SerenityColor::MAX,
+                });
+            }
+            embed = embed.color(Colour::from(color));
         }
 
         if let Some(footer_text) = &self.footer_text {
@@ -115,7 +159,10 @@ impl SerenityEmbed {
 
         if let Some(fields) = &self.fields {
             if fields.len() > 25 {
-                return Err(SerenityEmbedConvertError::TooManyFields);
+                return Err(SerenityEmbedConvertError::TooManyFields {
+                    actual: fields.len(),
+                    limit: 25,
+                });
             }
             // Explicitly create and pass (String, String, bool) to avoid ambiguity in `Into<String>` (inference failure due to multiple impls).
             let mapped = fields
@@ -128,6 +175,8 @@ impl SerenityEmbed {
     }
 }
 
+impl_convert!(SerenityEmbed => CreateEmbed, SerenityEmbedConvertError);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,7 +255,10 @@ mod tests {
         assert!(converted.is_err());
         assert!(matches!(
             converted,
-            Err(SerenityEmbedConvertError::TooLongDescription)
+            Err(SerenityEmbedConvertError::TooLongDescription {
+                actual: 5000,
+                limit: 4096
+            })
         ));
     }
 
@@ -221,6 +273,26 @@ mod tests {
         assert!(converted.is_ok());
     }
 
+    #[test]
+    fn test_embed_conversion_description_counts_characters() {
+        let cjk_description = "あ".repeat(2000); // 6000 bytes, but 2000 characters
+        let mock_embed = SerenityEmbed::builder()
+            .description(&cjk_description)
+            .build();
+        assert!(mock_embed.convert().is_ok());
+
+        let mock_embed = SerenityEmbed::builder()
+            .description("あ".repeat(5000))
+            .build();
+        assert!(matches!(
+            mock_embed.convert(),
+            Err(SerenityEmbedConvertError::TooLongDescription {
+                actual: 5000,
+                limit: 4096
+            })
+        ));
+    }
+
     #[test]
     fn test_embed_conversion_too_many_fields() {
         let mut fields = Vec::new();
@@ -239,7 +311,10 @@ mod tests {
         assert!(converted.is_err());
         assert!(matches!(
             converted,
-            Err(SerenityEmbedConvertError::TooManyFields)
+            Err(SerenityEmbedConvertError::TooManyFields {
+                actual: 30,
+                limit: 25
+            })
         ));
     }
 
@@ -260,4 +335,80 @@ mod tests {
 
         assert!(converted.is_ok());
     }
+
+    #[test]
+    fn test_embed_conversion_invalid_color() {
+        let mock_embed = SerenityEmbed::builder().color(0x1000000_u32).build();
+
+        let error = mock_embed.convert().unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityEmbedConvertError::InvalidColor {
+                actual: 0x1000000,
+                limit: 0xFFFFFF
+            }
+        ));
+        assert_eq!(error.field_path(), "color");
+
+        let deserialized: SerenityEmbed =
+            serde_json::from_value(serenity::json::json!({ "color": 0x1000000 })).unwrap();
+        assert!(matches!(
+            deserialized.convert(),
+            Err(SerenityEmbedConvertError::InvalidColor { .. })
+        ));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_embed_derive() {
+        #[derive(crate::IntoSerenityEmbed)]
+        struct MatchResult {
+            #[embed(title)]
+            name: String,
+            #[embed(description)]
+            summary: Option<String>,
+            #[embed(field, name = "Score", inline)]
+            score: u32,
+            #[embed(field)]
+            note: Option<String>,
+            #[embed(color)]
+            color: u32,
+            #[embed(timestamp)]
+            finished_at: Timestamp,
+            #[embed(skip)]
+            internal_id: u64,
+        }
+
+        let result = MatchResult {
+            name: MOCK_TEXT.to_string(),
+            summary: None,
+            score: 42,
+            note: Some(MOCK_TEXT.to_string()),
+            color: MOCK_COLOR,
+            finished_at: Timestamp::parse(MOCK_TIMESTAMP_STR).unwrap(),
+            internal_id: 1,
+        };
+
+        // serenity-builder
+        let mock_embed = SerenityEmbed::from(&result);
+        // serenity
+        let serenity_embed = CreateEmbed::default()
+            .title(MOCK_TEXT)
+            .timestamp(Timestamp::parse(MOCK_TIMESTAMP_STR).unwrap())
+            .color(Colour(MOCK_COLOR))
+            .fields(vec![
+                ("Score".to_string(), "42".to_string(), true),
+                ("note".to_string(), MOCK_TEXT.to_string(), false),
+            ]);
+
+        // `#[embed(skip)]` leaves the field out.
+        assert!(
+            mock_embed
+                .fields
+                .iter()
+                .flatten()
+                .all(|field| field.name != "internal_id")
+        );
+        assert_eq!(mock_embed.convert().unwrap(), serenity_embed);
+    }
 }
@@ -0,0 +1,178 @@
+use serenity::all::CreateAttachment;
+use serenity::json::{Value, json};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::emoji::SerenityEmoji;
+
+/// Errors that can occur when converting a [SerenityEmoji] to a request body for [serenity::http::Http::create_emoji].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityEmojiConvertError {
+    /**
+     * This occurs when the emoji name is not 2 to 32 alphanumeric characters or underscores, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name `{actual}` must be 2 to 32 alphanumeric characters or underscores.")]
+    InvalidName { actual: String },
+    /**
+     * This occurs when the emoji image exceeds 256 KB, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The image is too large: {actual} bytes (limit: {limit}).")]
+    TooLargeImage { actual: usize, limit: usize },
+}
+
+impl SerenityEmojiConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidName { .. } => ErrorKind::Format,
+            Self::TooLargeImage { .. } => ErrorKind::Size,
+        }
+    }
+
+    /// The path of the offending value within the emoji.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidName { .. } => "name",
+            Self::TooLargeImage { .. } => "image",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityEmoji {
+    /// Convert the emoji structure created in Builder into a request body usable in Serenity.
+    ///
+    /// Serenity does not provide a builder for emojis, and [serenity::model::id::GuildId::create_emoji] cannot restrict the emoji to roles.
+    /// The converted value is therefore the JSON body to pass to [serenity::http::Http::create_emoji].
+    ///
+    /// ```rs
+    /// let emoji = SerenityEmoji::builder()
+    ///   .name("party_parrot")
+    ///   .image(std::fs::read("party_parrot.gif")?)
+    ///   .build();
+    ///
+    /// let body = emoji.convert()?; // Result<Value, SerenityEmojiConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityEmoji using the builder
+    /// let emoji = SerenityEmoji::builder()
+    ///   .name("party_parrot")
+    ///   .image(std::fs::read("party_parrot.gif")?)
+    ///   .roles(vec![booster_role_id])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to the request body
+    /// let body = emoji.convert()?; // Result<Value, SerenityEmojiConvertError>
+    ///
+    /// // 3. Use the converted body in your bot
+    /// ctx.http.create_emoji(guild_id, &body, None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityEmojiConvertError::InvalidName]: The name is not 2 to 32 alphanumeric characters or underscores.
+    /// - [SerenityEmojiConvertError::TooLargeImage]: The image exceeds 256 KB.
+    pub fn convert(&self) -> Result<Value, SerenityEmojiConvertError> {
+        // Equivalent to the `^[A-Za-z0-9_]{2,32}$` pattern used by Discord.
+        let valid_name = (2..=32).contains(&self.name.len())
+            && self
+                .name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if !valid_name {
+            return Err(SerenityEmojiConvertError::InvalidName {
+                actual: self.name.clone(),
+            });
+        }
+
+        if self.image.len() > 256 * 1024 {
+            return Err(SerenityEmojiConvertError::TooLargeImage {
+                actual: self.image.len(),
+                limit: 256 * 1024,
+            });
+        }
+
+        // The file name is not sent; Discord only receives the base64 encoded data.
+        let image = CreateAttachment::bytes(self.image.clone(), "emoji.png").to_base64();
+        let roles = self.roles.clone().unwrap_or_default();
+
+        Ok(json!({
+            "name": self.name,
+            "image": image,
+            "roles": roles,
+        }))
+    }
+}
+
+impl_convert!(SerenityEmoji => Value, SerenityEmojiConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::RoleId;
+
+    use super::*;
+
+    static MOCK_NAME: &str = "party_parrot";
+    static MOCK_ROLE_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_emoji_conversion() {
+        let mock_emoji = SerenityEmoji::builder()
+            .name(MOCK_NAME)
+            .image(b"GIF89a".to_vec())
+            .roles(vec![RoleId::new(MOCK_ROLE_ID)])
+            .build();
+
+        let converted = mock_emoji.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            converted.unwrap(),
+            json!({
+                "name": MOCK_NAME,
+                "image": "data:image/png;base64,R0lGODlh",
+                "roles": [MOCK_ROLE_ID.to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_emoji_conversion_invalid_name() {
+        for name in ["a", "party-parrot", &"a".repeat(33)] {
+            let mock_emoji = SerenityEmoji::builder()
+                .name(name)
+                .image(b"GIF89a".to_vec())
+                .build();
+
+            assert!(matches!(
+                mock_emoji.convert(),
+                Err(SerenityEmojiConvertError::InvalidName { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_emoji_conversion_too_large_image() {
+        let mock_emoji = SerenityEmoji::builder()
+            .name(MOCK_NAME)
+            .image(vec![0; 256 * 1024 + 1])
+            .build();
+
+        let converted = mock_emoji.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityEmojiConvertError::TooLargeImage { .. })
+        ));
+    }
+}
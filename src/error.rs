@@ -0,0 +1,206 @@
+/// An error that can occur when converting any model of serenity-builder.
+///
+/// Every per-builder error (e.g. [crate::embed::SerenityEmbedConvertError]) converts into this type, so code handling several kinds of models can use a single error type.
+/// The available variants depend on the enabled features.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum SerenityBuilderError {
+    /// Failed to convert a [crate::model::embed::SerenityEmbed].
+    #[cfg(feature = "embed")]
+    #[error(transparent)]
+    Embed(#[from] crate::embed::SerenityEmbedConvertError),
+    /// Failed to convert a [crate::model::message::SerenityMessage].
+    #[cfg(feature = "message")]
+    #[error(transparent)]
+    Message(#[from] crate::message::SerenityMessageConvertError),
+    /// Failed to convert a [crate::model::message::SerenityMessage] with [crate::model::message::SerenityMessage::convert_strict].
+    #[cfg(all(feature = "message", feature = "mention"))]
+    #[error(transparent)]
+    StrictMessage(#[from] crate::message::SerenityStrictConvertError),
+    /// Failed to convert a [crate::model::thread::SerenityThread].
+    #[cfg(feature = "thread")]
+    #[error(transparent)]
+    Thread(#[from] crate::thread::SerenityThreadConvertError),
+    /// Failed to convert a [crate::model::forum::SerenityForumPost].
+    #[cfg(feature = "forum")]
+    #[error(transparent)]
+    ForumPost(#[from] crate::forum::SerenityForumPostConvertError),
+    /// Failed to convert a [crate::model::forum::SerenityForumTag].
+    #[cfg(feature = "forum")]
+    #[error(transparent)]
+    ForumTag(#[from] crate::forum::SerenityForumTagConvertError),
+    /// Failed to convert a [crate::model::channel::SerenityChannel].
+    #[cfg(feature = "channel")]
+    #[error(transparent)]
+    Channel(#[from] crate::channel::SerenityChannelConvertError),
+    /// Failed to convert a [crate::model::scheduled_event::SerenityScheduledEvent].
+    #[cfg(feature = "scheduled_event")]
+    #[error(transparent)]
+    ScheduledEvent(#[from] crate::scheduled_event::SerenityScheduledEventConvertError),
+    /// Failed to convert a [crate::model::invite::SerenityInvite].
+    #[cfg(feature = "invite")]
+    #[error(transparent)]
+    Invite(#[from] crate::invite::SerenityInviteConvertError),
+    /// Failed to convert a [crate::model::sticker::SerenitySticker].
+    #[cfg(feature = "sticker")]
+    #[error(transparent)]
+    Sticker(#[from] crate::sticker::SerenityStickerConvertError),
+    /// Failed to convert a [crate::model::role::SerenityRole].
+    #[cfg(feature = "role")]
+    #[error(transparent)]
+    Role(#[from] crate::role::SerenityRoleConvertError),
+    /// Failed to convert a [crate::model::emoji::SerenityEmoji].
+    #[cfg(feature = "emoji")]
+    #[error(transparent)]
+    Emoji(#[from] crate::emoji::SerenityEmojiConvertError),
+    /// Failed to convert a [crate::model::automod::SerenityAutoModRule].
+    #[cfg(feature = "automod")]
+    #[error(transparent)]
+    AutoModRule(#[from] crate::automod::SerenityAutoModRuleConvertError),
+    /// Failed to convert a [crate::model::stage::SerenityStageInstance].
+    #[cfg(feature = "stage")]
+    #[error(transparent)]
+    StageInstance(#[from] crate::stage::SerenityStageInstanceConvertError),
+    /// Failed to convert a [crate::model::stage::SerenityVoiceStatus].
+    #[cfg(feature = "stage")]
+    #[error(transparent)]
+    VoiceStatus(#[from] crate::stage::SerenityVoiceStatusConvertError),
+    /// Failed to convert a [crate::model::onboarding::SerenityWelcomeScreen].
+    #[cfg(feature = "onboarding")]
+    #[error(transparent)]
+    WelcomeScreen(#[from] crate::onboarding::SerenityWelcomeScreenConvertError),
+    /// Failed to convert a [crate::model::onboarding::SerenityOnboarding].
+    #[cfg(feature = "onboarding")]
+    #[error(transparent)]
+    Onboarding(#[from] crate::onboarding::SerenityOnboardingConvertError),
+    /// Failed to convert a [crate::model::command::SerenityCommand].
+    #[cfg(feature = "command")]
+    #[error(transparent)]
+    Command(#[from] crate::command::SerenityCommandConvertError),
+    /// Failed to merge or patch a [crate::model::embed::SerenityEmbed] or a [crate::model::message::SerenityMessage].
+    #[cfg(feature = "patch")]
+    #[error(transparent)]
+    Patch(#[from] crate::patch::SerenityPatchError),
+}
+
+impl SerenityBuilderError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            #[cfg(feature = "embed")]
+            Self::Embed(ref e) => e.kind(),
+            #[cfg(feature = "message")]
+            Self::Message(ref e) => e.kind(),
+            #[cfg(all(feature = "message", feature = "mention"))]
+            Self::StrictMessage(ref e) => e.kind(),
+            #[cfg(feature = "thread")]
+            Self::Thread(ref e) => e.kind(),
+            #[cfg(feature = "forum")]
+            Self::ForumPost(ref e) => e.kind(),
+            #[cfg(feature = "forum")]
+            Self::ForumTag(ref e) => e.kind(),
+            #[cfg(feature = "channel")]
+            Self::Channel(ref e) => e.kind(),
+            #[cfg(feature = "scheduled_event")]
+            Self::ScheduledEvent(ref e) => e.kind(),
+            #[cfg(feature = "invite")]
+            Self::Invite(ref e) => e.kind(),
+            #[cfg(feature = "sticker")]
+            Self::Sticker(ref e) => e.kind(),
+            #[cfg(feature = "role")]
+            Self::Role(ref e) => e.kind(),
+            #[cfg(feature = "emoji")]
+            Self::Emoji(ref e) => e.kind(),
+            #[cfg(feature = "automod")]
+            Self::AutoModRule(ref e) => e.kind(),
+            #[cfg(feature = "stage")]
+            Self::StageInstance(ref e) => e.kind(),
+            #[cfg(feature = "stage")]
+            Self::VoiceStatus(ref e) => e.kind(),
+            #[cfg(feature = "onboarding")]
+            Self::WelcomeScreen(ref e) => e.kind(),
+            #[cfg(feature = "onboarding")]
+            Self::Onboarding(ref e) => e.kind(),
+            #[cfg(feature = "command")]
+            Self::Command(ref e) => e.kind(),
+            #[cfg(feature = "patch")]
+            Self::Patch(ref e) => e.kind(),
+        }
+    }
+
+    /// The path of the offending value, e.g. `embeds[1].description`.
+    pub fn field_path(&self) -> String {
+        match *self {
+            #[cfg(feature = "embed")]
+            Self::Embed(ref e) => e.field_path(),
+            #[cfg(feature = "message")]
+            Self::Message(ref e) => e.field_path(),
+            #[cfg(all(feature = "message", feature = "mention"))]
+            Self::StrictMessage(ref e) => e.field_path(),
+            #[cfg(feature = "thread")]
+            Self::Thread(ref e) => e.field_path(),
+            #[cfg(feature = "forum")]
+            Self::ForumPost(ref e) => e.field_path(),
+            #[cfg(feature = "forum")]
+            Self::ForumTag(ref e) => e.field_path(),
+            #[cfg(feature = "channel")]
+            Self::Channel(ref e) => e.field_path(),
+            #[cfg(feature = "scheduled_event")]
+            Self::ScheduledEvent(ref e) => e.field_path(),
+            #[cfg(feature = "invite")]
+            Self::Invite(ref e) => e.field_path(),
+            #[cfg(feature = "sticker")]
+            Self::Sticker(ref e) => e.field_path(),
+            #[cfg(feature = "role")]
+            Self::Role(ref e) => e.field_path(),
+            #[cfg(feature = "emoji")]
+            Self::Emoji(ref e) => e.field_path(),
+            #[cfg(feature = "automod")]
+            Self::AutoModRule(ref e) => e.field_path(),
+            #[cfg(feature = "stage")]
+            Self::StageInstance(ref e) => e.field_path(),
+            #[cfg(feature = "stage")]
+            Self::VoiceStatus(ref e) => e.field_path(),
+            #[cfg(feature = "onboarding")]
+            Self::WelcomeScreen(ref e) => e.field_path(),
+            #[cfg(feature = "onboarding")]
+            Self::Onboarding(ref e) => e.field_path(),
+            #[cfg(feature = "command")]
+            Self::Command(ref e) => e.field_path(),
+            #[cfg(feature = "patch")]
+            Self::Patch(ref e) => e.field_path(),
+        }
+    }
+}
+
+/// The category of a conversion error, for programmatic matching.
+///
+/// Every conversion error provides its category with `kind()` and the path of the offending value with `field_path()`.
+///
+/// ```rs
+/// match message.convert() {
+///     Err(e) if e.kind() == ErrorKind::Length => { /* truncate `e.field_path()` and retry */ }
+///     Err(e) => return Err(e.into()),
+///     Ok(message) => { /* ... */ }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A text value is longer or shorter than allowed.
+    Length,
+    /// A list has more or fewer entries than allowed.
+    Count,
+    /// A numeric value is outside of the allowed range.
+    Range,
+    /// A file or image is larger than allowed.
+    Size,
+    /// A required value is missing.
+    Missing,
+    /// Two or more values cannot be used together.
+    Conflict,
+    /// A value is not applicable in this context (e.g. to this channel type).
+    Inapplicable,
+    /// A value does not have the expected format.
+    Format,
+}
@@ -0,0 +1,337 @@
+use serenity::all::{CreateForumPost, CreateForumTag, ReactionType};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::forum::{SerenityForumPost, SerenityForumTag, SerenityForumTagEmoji};
+
+/// Errors that can occur when converting a [SerenityForumPost] to a [serenity::all::CreateForumPost].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityForumPostConvertError {
+    /**
+     * This occurs when the post title is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The title must be between {min} and {max} characters, but is {actual}.")]
+    InvalidTitleLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when more than 5 tags are applied to the post, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("There are too many applied tags: {actual} tags (limit: {limit}).")]
+    TooManyAppliedTags { actual: usize, limit: usize },
+    /**
+     * This occurs when the slowmode exceeds 21600 seconds, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The slowmode is too long: {actual} seconds (limit: {limit}).")]
+    TooLongSlowmode { actual: u16, limit: u16 },
+    /**
+     * This occurs when there is an error converting the starter message.
+     * The specific error details are encapsulated in the [crate::message::SerenityMessageConvertError].
+     */
+    #[error(transparent)]
+    MessageConvertError(#[from] crate::message::SerenityMessageConvertError),
+}
+
+impl SerenityForumPostConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidTitleLength { .. } => ErrorKind::Length,
+            Self::TooManyAppliedTags { .. } => ErrorKind::Count,
+            Self::TooLongSlowmode { .. } => ErrorKind::Range,
+            Self::MessageConvertError(e) => e.kind(),
+        }
+    }
+
+    /// The path of the offending value within the forum post, e.g. `message.embeds[0].description`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidTitleLength { .. } => "title".to_string(),
+            Self::TooManyAppliedTags { .. } => "applied_tags".to_string(),
+            Self::TooLongSlowmode { .. } => "slowmode".to_string(),
+            Self::MessageConvertError(e) => format!("message.{}", e.field_path()),
+        }
+    }
+}
+
+/// Errors that can occur when converting a [SerenityForumTag] to a [serenity::all::CreateForumTag].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityForumTagConvertError {
+    /**
+     * This occurs when the tag name exceeds 20 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name is too long: {actual} characters (limit: {limit}).")]
+    TooLongName { actual: usize, limit: usize },
+}
+
+impl SerenityForumTagConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongName { .. } => ErrorKind::Length,
+        }
+    }
+
+    /// The path of the offending value within the forum tag.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongName { .. } => "name",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityForumPost {
+    /// Convert the forum post structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let post = SerenityForumPost::builder()
+    ///   .title("This is a test post.")
+    ///   .message(SerenityMessage::builder().content("Hello!").build())
+    ///   .build();
+    ///
+    /// let serenity_post = post.convert()?; // Result<CreateForumPost, SerenityForumPostConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityForumPost using the builder
+    /// let post = SerenityForumPost::builder()
+    ///   .title("This is a test post.")
+    ///   .message(SerenityMessage::builder().content("Hello!").build())
+    ///   .applied_tags(vec![ForumTagId::new(123456789012345678)])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateForumPost
+    /// let serenity_post = post.convert()?; // Result<CreateForumPost, SerenityForumPostConvertError>
+    ///
+    /// // 3. Use the converted post in your bot
+    /// forum_channel_id.create_forum_post(&ctx.http, serenity_post).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityForumPostConvertError::InvalidTitleLength]: The title is empty or exceeds 100 characters.
+    /// - [SerenityForumPostConvertError::TooManyAppliedTags]: More than 5 tags are applied.
+    /// - [SerenityForumPostConvertError::TooLongSlowmode]: The slowmode exceeds 21600 seconds.
+    /// - [SerenityForumPostConvertError::MessageConvertError]: Failed to perform internal conversion for the starter message. (error [crate::message::SerenityMessageConvertError] reported by thiserror)
+    pub fn convert(&self) -> Result<CreateForumPost<'static>, SerenityForumPostConvertError> {
+        let title_length = self.title.encode_utf16().count();
+        if !(1..=100).contains(&title_length) {
+            return Err(SerenityForumPostConvertError::InvalidTitleLength {
+                actual: title_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let message = self.message.convert()?;
+        let mut post = CreateForumPost::new(&self.title, message);
+
+        if let Some(applied_tags) = &self.applied_tags {
+            if applied_tags.len() > 5 {
+                return Err(SerenityForumPostConvertError::TooManyAppliedTags {
+                    actual: applied_tags.len(),
+                    limit: 5,
+                });
+            }
+            post = post.set_applied_tags(applied_tags.clone());
+        }
+
+        if let Some(duration) = self.auto_archive_duration {
+            post = post.auto_archive_duration(duration.into());
+        }
+
+        if let Some(slowmode) = self.slowmode {
+            if slowmode > 21600 {
+                return Err(SerenityForumPostConvertError::TooLongSlowmode {
+                    actual: slowmode,
+                    limit: 21600,
+                });
+            }
+            post = post.rate_limit_per_user(slowmode);
+        }
+
+        Ok(post)
+    }
+}
+
+impl SerenityForumTag {
+    /// Convert the forum tag structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let tag = SerenityForumTag::builder()
+    ///   .name("Solved")
+    ///   .emoji(SerenityForumTagEmoji::Unicode("✅".to_string()))
+    ///   .build();
+    ///
+    /// let serenity_tag = tag.convert()?; // Result<CreateForumTag, SerenityForumTagConvertError>
+    ///
+    /// forum_channel_id.edit(&ctx.http, EditChannel::new().available_tags(vec![serenity_tag])).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityForumTagConvertError::TooLongName]: The name exceeds 20 characters.
+    pub fn convert(&self) -> Result<CreateForumTag, SerenityForumTagConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if name_length > 20 {
+            return Err(SerenityForumTagConvertError::TooLongName {
+                actual: name_length,
+                limit: 20,
+            });
+        }
+
+        let mut tag = CreateForumTag::new(&self.name).moderated(self.moderated);
+
+        if let Some(emoji) = &self.emoji {
+            let reaction = match emoji {
+                SerenityForumTagEmoji::Custom(id) => ReactionType::Custom {
+                    animated: false,
+                    id: *id,
+                    name: None,
+                },
+                SerenityForumTagEmoji::Unicode(name) => ReactionType::Unicode(name.clone()),
+            };
+            tag = tag.emoji(reaction);
+        }
+
+        Ok(tag)
+    }
+}
+
+impl_convert!(SerenityForumPost => CreateForumPost<'static>, SerenityForumPostConvertError);
+impl_convert!(SerenityForumTag => CreateForumTag, SerenityForumTagConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{AutoArchiveDuration, CreateMessage, EmojiId, ForumTagId};
+
+    use super::*;
+    use crate::model::message::SerenityMessage;
+    use crate::model::thread::SerenityThreadAutoArchiveDuration;
+
+    static MOCK_TEXT: &str = "This is a test post.";
+    static MOCK_TAG_NAME: &str = "Solved";
+    static MOCK_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_forum_post_conversion() {
+        // serenity-builder
+        let mock_post = SerenityForumPost::builder()
+            .title(MOCK_TEXT)
+            .message(SerenityMessage::builder().content(MOCK_TEXT).build())
+            .applied_tags(vec![ForumTagId::new(MOCK_ID)])
+            .auto_archive_duration(SerenityThreadAutoArchiveDuration::OneDay)
+            .slowmode(30_u16)
+            .build();
+        // serenity
+        let serenity_post = CreateForumPost::new(
+            MOCK_TEXT,
+            CreateMessage::default().content(MOCK_TEXT).tts(false),
+        )
+        .set_applied_tags(vec![ForumTagId::new(MOCK_ID)])
+        .auto_archive_duration(AutoArchiveDuration::OneDay)
+        .rate_limit_per_user(30);
+
+        let converted = mock_post.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_post).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_forum_post_conversion_too_many_applied_tags() {
+        let mock_post = SerenityForumPost::builder()
+            .title(MOCK_TEXT)
+            .message(SerenityMessage::builder().content(MOCK_TEXT).build())
+            .applied_tags(
+                (0..6)
+                    .map(|i| ForumTagId::new(MOCK_ID + i))
+                    .collect::<Vec<_>>(),
+            )
+            .build();
+
+        let converted = mock_post.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityForumPostConvertError::TooManyAppliedTags {
+                actual: 6,
+                limit: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn test_forum_post_conversion_invalid_message() {
+        let mock_post = SerenityForumPost::builder()
+            .title(MOCK_TEXT)
+            .message(SerenityMessage::builder().content("a".repeat(2001)).build())
+            .build();
+
+        let error = mock_post.convert().unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityForumPostConvertError::MessageConvertError(_)
+        ));
+        assert_eq!(error.field_path(), "message.content");
+    }
+
+    #[test]
+    fn test_forum_tag_conversion() {
+        // serenity-builder
+        let mock_tag = SerenityForumTag::builder()
+            .name(MOCK_TAG_NAME)
+            .moderated(true)
+            .emoji(SerenityForumTagEmoji::Custom(EmojiId::new(MOCK_ID)))
+            .build();
+        // serenity
+        let serenity_tag = CreateForumTag::new(MOCK_TAG_NAME)
+            .moderated(true)
+            .emoji(EmojiId::new(MOCK_ID));
+
+        let converted = mock_tag.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_tag).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_forum_tag_conversion_too_long_name() {
+        let mock_tag = SerenityForumTag::builder().name("a".repeat(21)).build();
+
+        let converted = mock_tag.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityForumTagConvertError::TooLongName {
+                actual: 21,
+                limit: 20
+            })
+        ));
+    }
+}
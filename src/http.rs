@@ -0,0 +1,513 @@
+use serenity::all::{
+    CacheHttp, ChannelId, CreateAllowedMentions, ExecuteWebhook, Message, MessageId, Webhook,
+};
+
+use crate::error::SerenityBuilderError;
+use crate::model::message::{SerenityMessage, SerenityMessageMentionType};
+
+/// Errors that can occur when converting a model and sending it to Discord.
+#[derive(thiserror::Error, Debug)]
+pub enum SerenitySendError {
+    /**
+     * This occurs when the conversion fails. Nothing is sent to Discord.
+     * The specific error details are encapsulated in the [crate::error::SerenityBuilderError].
+     */
+    #[error(transparent)]
+    Convert(#[from] SerenityBuilderError),
+    /**
+     * This occurs when the request fails, e.g. because Discord rejected it or the connection failed.
+     * The specific error details are encapsulated in the [serenity::Error], boxed since it is large.
+     */
+    #[error(transparent)]
+    Http(Box<serenity::Error>),
+    /**
+     * This occurs when a value is set that webhooks cannot send.
+     * Webhooks can send neither stickers nor replies.
+     */
+    #[error("The `{field}` value cannot be sent through a webhook.")]
+    UnsupportedByWebhook { field: &'static str },
+}
+
+impl From<serenity::Error> for SerenitySendError {
+    fn from(error: serenity::Error) -> Self {
+        Self::Http(Box::new(error))
+    }
+}
+
+impl From<crate::message::SerenityMessageConvertError> for SerenitySendError {
+    fn from(error: crate::message::SerenityMessageConvertError) -> Self {
+        Self::Convert(error.into())
+    }
+}
+
+impl SerenityMessage {
+    /// Convert the message and send it to a channel.
+    ///
+    /// ```rs
+    /// let message = SerenityMessage::builder()
+    ///   .content("This is a test message.")
+    ///   .build();
+    ///
+    /// let sent = message.send_to(channel_id, &ctx).await?; // Result<Message, SerenitySendError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenitySendError::Convert]: The conversion failed. See [SerenityMessage::convert].
+    /// - [SerenitySendError::Http]: The request failed.
+    pub async fn send_to(
+        &self,
+        channel_id: impl Into<ChannelId>,
+        cache_http: impl CacheHttp,
+    ) -> Result<Message, SerenitySendError> {
+        let message = self.convert()?;
+        Ok(channel_id.into().send_message(cache_http, message).await?)
+    }
+
+    /// Convert the message and execute a webhook with it.
+    ///
+    /// If `wait` is `true`, Discord returns the sent message. Otherwise, `None` is returned.
+    ///
+    /// ```rs
+    /// let webhook = Webhook::from_url(&ctx.http, webhook_url).await?;
+    /// let message = SerenityMessage::builder()
+    ///   .content("This is a test message.")
+    ///   .build();
+    ///
+    /// message.execute_webhook(&webhook, &ctx, false).await?; // Result<Option<Message>, SerenitySendError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenitySendError::UnsupportedByWebhook]: `sticker_ids` or a reply is set.
+    /// - [SerenitySendError::Convert]: The conversion failed. See [SerenityMessage::convert].
+    /// - [SerenitySendError::Http]: The request failed, or the webhook has no token.
+    pub async fn execute_webhook(
+        &self,
+        webhook: &Webhook,
+        cache_http: impl CacheHttp,
+        wait: bool,
+    ) -> Result<Option<Message>, SerenitySendError> {
+        let webhook_message = self.convert_webhook()?;
+        Ok(webhook.execute(cache_http, wait, webhook_message).await?)
+    }
+
+    /// Convert the message and send it to a channel, splitting content longer than 2000 characters into several messages.
+    ///
+    /// The messages are sent in order. The content is split at line breaks where possible, then at whitespace.
+    /// Embeds and stickers are attached to the last message, and a reply set with [SerenityMessageMentionType::Reply] is attached to the first.
+    /// The later messages of such a reply are sent with allowed mentions that ping nobody.
+    /// If `reply_to_first` is `true`, the later messages are sent as replies to the first one.
+    ///
+    /// Every message is converted before the first one is sent, so a conversion error means nothing was sent.
+    /// If a request fails, the messages sent before it are not deleted.
+    ///
+    /// ```rs
+    /// let message = SerenityMessage::builder()
+    ///   .content(very_long_log)
+    ///   .build();
+    ///
+    /// let ids = message.send_chunked_to(channel_id, &ctx, true).await?; // Result<Vec<MessageId>, SerenitySendError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenitySendError::Convert]: The conversion failed. See [SerenityMessage::convert]. [crate::message::SerenityMessageConvertError::TooLongContent] is never returned.
+    /// - [SerenitySendError::Http]: A request failed.
+    pub async fn send_chunked_to(
+        &self,
+        channel_id: impl Into<ChannelId>,
+        cache_http: impl CacheHttp,
+        reply_to_first: bool,
+    ) -> Result<Vec<MessageId>, SerenitySendError> {
+        let channel_id = channel_id.into();
+
+        let chunks = match &self.content {
+            Some(content) => split_content(content, 2000),
+            None => vec![],
+        };
+        if chunks.len() <= 1 {
+            let message = self.send_to(channel_id, cache_http).await?;
+            return Ok(vec![message.id]);
+        }
+
+        let last = chunks.len() - 1;
+        let is_reply = matches!(
+            self.mention_type,
+            Some(SerenityMessageMentionType::Reply(_))
+        );
+        let mut messages = Vec::with_capacity(chunks.len());
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let part = SerenityMessage {
+                content: Some(chunk.to_string()),
+                embeds: self.embeds.clone().filter(|_| index == last),
+                tts: self.tts,
+                // A reply only applies to the first message.
+                mention_type: self
+                    .mention_type
+                    .clone()
+                    .filter(|_| index == 0 || !is_reply),
+                sticker_ids: self.sticker_ids.clone().filter(|_| index == last),
+            };
+            let mut message = part.convert()?;
+            if is_reply && index > 0 {
+                // Without allowed mentions, Discord would ping everything mentioned in the later messages.
+                message = message.allowed_mentions(CreateAllowedMentions::new());
+            }
+            messages.push(message);
+        }
+
+        let mut first: Option<Message> = None;
+        let mut ids = Vec::with_capacity(messages.len());
+        for mut message in messages {
+            if reply_to_first && let Some(first) = &first {
+                message = message.reference_message(first);
+            }
+            let sent = channel_id.send_message(&cache_http, message).await?;
+            ids.push(sent.id);
+            first.get_or_insert(sent);
+        }
+
+        Ok(ids)
+    }
+
+    /// Convert the message into the payload of a webhook execution.
+    fn convert_webhook(&self) -> Result<ExecuteWebhook, SerenitySendError> {
+        if self.sticker_ids.is_some() {
+            return Err(SerenitySendError::UnsupportedByWebhook {
+                field: "sticker_ids",
+            });
+        }
+
+        if let Some(SerenityMessageMentionType::Reply(_)) = &self.mention_type {
+            return Err(SerenitySendError::UnsupportedByWebhook {
+                field: "mention_type",
+            });
+        }
+
+        let mut webhook_message = ExecuteWebhook::new()
+            .embeds(self.convert_embeds()?)
+            .tts(self.tts);
+
+        if let Some(content) = self.validated_content()? {
+            webhook_message = webhook_message.content(content);
+        }
+
+        if let Some(allowed_mentions) = self.allowed_mentions() {
+            webhook_message = webhook_message.allowed_mentions(allowed_mentions);
+        }
+
+        Ok(webhook_message)
+    }
+}
+
+/// Split the content into chunks of at most `limit` UTF-16 code units, preferring to split after a line break, then after whitespace.
+fn split_content(content: &str, limit: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = content;
+
+    while rest.encode_utf16().count() > limit {
+        // The longest prefix within the limit, never splitting a character.
+        let mut units = 0;
+        let mut end = 0;
+        for (index, c) in rest.char_indices() {
+            units += c.len_utf16();
+            if units > limit {
+                break;
+            }
+            end = index + c.len_utf8();
+        }
+
+        let head = &rest[..end];
+        let split = head
+            .rfind('\n')
+            .or_else(|| head.rfind(char::is_whitespace))
+            .map(|index| index + head[index..].chars().next().map_or(1, char::len_utf8))
+            .unwrap_or(end);
+
+        chunks.push(&rest[..split]);
+        rest = &rest[split..];
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    use serenity::all::{HttpBuilder, StickerId};
+    use serenity::http::Http;
+    use serenity::json::{Value, json};
+
+    use super::*;
+    use crate::model::embed::SerenityEmbed;
+
+    static MOCK_TEXT: &str = "This is a test message.";
+    static MOCK_CHANNEL_ID: u64 = 123456789012345678;
+
+    /// A request received by the mock server.
+    struct Received {
+        method: String,
+        path: String,
+        body: Value,
+    }
+
+    /// Start a local HTTP server that stands in for Discord, answering every request with a message.
+    fn mock_server() -> (Http, Arc<Mutex<Vec<Received>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let log = Arc::clone(&received);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                // Serve requests until the client closes the connection.
+                loop {
+                    let mut request_line = String::new();
+                    if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                        break;
+                    }
+                    let mut parts = request_line.split_whitespace();
+                    let method = parts.next().unwrap().to_string();
+                    let path = parts.next().unwrap().to_string();
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header == "\r\n" {
+                            break;
+                        }
+                        if let Some((name, value)) = header.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+                    let id = 100 + log.lock().unwrap().len() as u64;
+                    let response = mock_message(id, &body).to_string();
+                    log.lock().unwrap().push(Received { method, path, body });
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                        response.len(),
+                        response
+                    )
+                    .unwrap();
+                }
+            }
+        });
+
+        let http = HttpBuilder::new("Bot mock-token")
+            .proxy(format!("http://{address}"))
+            .ratelimiter_disabled(true)
+            .build();
+        (http, received)
+    }
+
+    /// A message as Discord would return it, echoing the content of the request.
+    fn mock_message(id: u64, body: &Value) -> Value {
+        json!({
+            "id": id.to_string(),
+            "channel_id": MOCK_CHANNEL_ID.to_string(),
+            "author": {
+                "id": "2",
+                "username": "serenity-builder",
+                "discriminator": "0000",
+                "avatar": null,
+            },
+            "content": body.get("content").cloned().unwrap_or(json!("")),
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        })
+    }
+
+    fn mock_webhook() -> Webhook {
+        serde_json::from_value(json!({
+            "id": "3",
+            "type": 1,
+            "name": "serenity-builder",
+            "avatar": null,
+            "token": "mock-webhook-token",
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_message_send_to() {
+        let (http, received) = mock_server();
+        let mock_message = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![SerenityEmbed::builder().title(MOCK_TEXT).build()])
+            .build();
+
+        let sent = mock_message
+            .send_to(ChannelId::new(MOCK_CHANNEL_ID), &http)
+            .await
+            .unwrap();
+        assert_eq!(sent.content, MOCK_TEXT);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(
+            received[0].path,
+            format!("/api/v10/channels/{MOCK_CHANNEL_ID}/messages")
+        );
+        assert_eq!(received[0].body["content"], MOCK_TEXT);
+        assert_eq!(received[0].body["embeds"][0]["title"], MOCK_TEXT);
+    }
+
+    #[tokio::test]
+    async fn test_message_send_to_conversion_error() {
+        let (http, received) = mock_server();
+        let mock_message = SerenityMessage::builder().content("a".repeat(2001)).build();
+
+        let sent = mock_message
+            .send_to(ChannelId::new(MOCK_CHANNEL_ID), &http)
+            .await;
+        assert!(matches!(sent, Err(SerenitySendError::Convert(_))));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_message_execute_webhook() {
+        let (http, received) = mock_server();
+        let mock_message = SerenityMessage::builder().content(MOCK_TEXT).build();
+
+        let sent = mock_message
+            .execute_webhook(&mock_webhook(), &http, true)
+            .await
+            .unwrap();
+        assert!(sent.is_some());
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (path, query) = received[0].path.split_once('?').unwrap();
+        assert_eq!(path, "/api/v10/webhooks/3/mock-webhook-token");
+        assert!(query.contains("wait=true"));
+        assert_eq!(received[0].body["content"], MOCK_TEXT);
+    }
+
+    #[tokio::test]
+    async fn test_message_execute_webhook_unsupported() {
+        let (http, received) = mock_server();
+        let mock_message = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .sticker_ids(vec![StickerId::new(MOCK_CHANNEL_ID)])
+            .build();
+
+        let sent = mock_message
+            .execute_webhook(&mock_webhook(), &http, true)
+            .await;
+        assert!(matches!(
+            sent,
+            Err(SerenitySendError::UnsupportedByWebhook {
+                field: "sticker_ids"
+            })
+        ));
+        assert!(received.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_split_content() {
+        assert_eq!(split_content("abc", 5), vec!["abc"]);
+        assert_eq!(split_content("aaa\nbbb ccc", 8), vec!["aaa\n", "bbb ccc"]);
+        assert_eq!(split_content("aaa bbb ccc", 8), vec!["aaa bbb ", "ccc"]);
+        assert_eq!(split_content("aaaaaaaaaa", 4), vec!["aaaa", "aaaa", "aa"]);
+        // Emoji are two UTF-16 code units and are never split.
+        assert_eq!(split_content("😀😀😀", 3), vec!["😀", "😀", "😀"]);
+    }
+
+    #[tokio::test]
+    async fn test_message_send_chunked_to() {
+        let (http, received) = mock_server();
+        let content = format!("{}\n{}", "a".repeat(1500), "b".repeat(1500));
+        let mock_message = SerenityMessage::builder()
+            .content(content)
+            .embeds(vec![SerenityEmbed::builder().title(MOCK_TEXT).build()])
+            .build();
+
+        let ids = mock_message
+            .send_chunked_to(ChannelId::new(MOCK_CHANNEL_ID), &http, true)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![MessageId::new(100), MessageId::new(101)]);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(
+            received[0].body["content"],
+            format!("{}\n", "a".repeat(1500))
+        );
+        assert_eq!(received[0].body["embeds"], json!([]));
+        assert!(received[0].body.get("message_reference").is_none());
+        assert_eq!(received[1].body["content"], "b".repeat(1500));
+        assert_eq!(received[1].body["embeds"][0]["title"], MOCK_TEXT);
+        assert_eq!(received[1].body["message_reference"]["message_id"], "100");
+    }
+
+    #[tokio::test]
+    async fn test_message_send_chunked_to_reply() {
+        let (http, received) = mock_server();
+        let reply_to: Message = serde_json::from_value(mock_message(1, &json!({}))).unwrap();
+        let content = format!("{}\n@everyone {}", "a".repeat(1500), "b".repeat(1500));
+        let mock_message = SerenityMessage::builder()
+            .content(content)
+            .mention_type(SerenityMessageMentionType::Reply(Box::new(reply_to)))
+            .build();
+
+        mock_message
+            .send_chunked_to(ChannelId::new(MOCK_CHANNEL_ID), &http, false)
+            .await
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].body["message_reference"]["message_id"], "1");
+        assert_eq!(received[0].body["allowed_mentions"]["replied_user"], true);
+        assert!(received[1].body.get("message_reference").is_none());
+        assert_eq!(received[1].body["allowed_mentions"]["parse"], json!([]));
+        assert_ne!(received[1].body["allowed_mentions"]["replied_user"], true);
+    }
+
+    #[tokio::test]
+    async fn test_message_send_chunked_to_short_content() {
+        let (http, received) = mock_server();
+        let mock_message = SerenityMessage::builder().content(MOCK_TEXT).build();
+
+        let ids = mock_message
+            .send_chunked_to(ChannelId::new(MOCK_CHANNEL_ID), &http, true)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![MessageId::new(100)]);
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,273 @@
+use serenity::all::CreateInvite;
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::invite::{SerenityInvite, SerenityInviteMaxAge, SerenityInviteTargetType};
+
+/// Errors that can occur when converting a [SerenityInvite] to a [serenity::all::CreateInvite].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityInviteConvertError {
+    /**
+     * This occurs when the max age exceeds 604800 seconds (7 days), which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The max age is too long: {actual} seconds (limit: {limit}).")]
+    TooLongMaxAge { actual: u32, limit: u32 },
+    /**
+     * This occurs when the max uses exceeds 100, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The max uses is too large: {actual} (limit: {limit}).")]
+    TooManyMaxUses { actual: u8, limit: u8 },
+    /// This occurs when a target user or application ID is set without a `target_type`.
+    #[error("A target ID requires a target type.")]
+    MissingTargetType,
+    /// This occurs when the target type is [SerenityInviteTargetType::Stream] but no `target_user_id` is set.
+    #[error("Stream invites require a target user ID.")]
+    MissingTargetUserId,
+    /// This occurs when the target type is [SerenityInviteTargetType::EmbeddedApplication] but no `target_application_id` is set.
+    #[error("Embedded application invites require a target application ID.")]
+    MissingTargetApplicationId,
+    /**
+     * This occurs when the target ID does not match the target type.
+     * `target_user_id` and `target_application_id` are mutually exclusive. The name of the offending value is included.
+     */
+    #[error("The `{field}` value does not match the target type.")]
+    MismatchedTargetId { field: &'static str },
+}
+
+impl SerenityInviteConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongMaxAge { .. } | Self::TooManyMaxUses { .. } => ErrorKind::Range,
+            Self::MissingTargetType
+            | Self::MissingTargetUserId
+            | Self::MissingTargetApplicationId => ErrorKind::Missing,
+            Self::MismatchedTargetId { .. } => ErrorKind::Conflict,
+        }
+    }
+
+    /// The path of the offending value within the invite.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongMaxAge { .. } => "max_age",
+            Self::TooManyMaxUses { .. } => "max_uses",
+            Self::MissingTargetType => "target_type",
+            Self::MissingTargetUserId => "target_user_id",
+            Self::MissingTargetApplicationId => "target_application_id",
+            Self::MismatchedTargetId { field } => field,
+        }
+        .to_string()
+    }
+}
+
+impl SerenityInvite {
+    /// Convert the invite structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let invite = SerenityInvite::builder()
+    ///   .max_age(SerenityInviteMaxAge::Never)
+    ///   .max_uses(10_u8)
+    ///   .build();
+    ///
+    /// let serenity_invite = invite.convert()?; // Result<CreateInvite, SerenityInviteConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityInvite using the builder
+    /// let invite = SerenityInvite::builder()
+    ///   .max_age(SerenityInviteMaxAge::Seconds(3600))
+    ///   .unique(true)
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateInvite
+    /// let serenity_invite = invite.convert()?; // Result<CreateInvite, SerenityInviteConvertError>
+    ///
+    /// // 3. Use the converted invite in your bot
+    /// let invite = channel_id.create_invite(&ctx.http, serenity_invite).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityInviteConvertError::TooLongMaxAge]: The max age exceeds 7 days.
+    /// - [SerenityInviteConvertError::TooManyMaxUses]: The max uses exceeds 100.
+    /// - [SerenityInviteConvertError::MissingTargetType]: A target ID is set without a target type.
+    /// - [SerenityInviteConvertError::MissingTargetUserId]: A stream invite has no target user ID.
+    /// - [SerenityInviteConvertError::MissingTargetApplicationId]: An embedded application invite has no target application ID.
+    /// - [SerenityInviteConvertError::MismatchedTargetId]: The target ID does not match the target type.
+    pub fn convert(&self) -> Result<CreateInvite<'static>, SerenityInviteConvertError> {
+        let mut invite = CreateInvite::new();
+
+        if let Some(max_age) = self.max_age {
+            let seconds = match max_age {
+                SerenityInviteMaxAge::Never => 0,
+                SerenityInviteMaxAge::Seconds(seconds) => {
+                    if seconds > 604800 {
+                        return Err(SerenityInviteConvertError::TooLongMaxAge {
+                            actual: seconds,
+                            limit: 604800,
+                        });
+                    }
+                    seconds
+                }
+            };
+            invite = invite.max_age(seconds);
+        }
+
+        if let Some(max_uses) = self.max_uses {
+            if max_uses > 100 {
+                return Err(SerenityInviteConvertError::TooManyMaxUses {
+                    actual: max_uses,
+                    limit: 100,
+                });
+            }
+            invite = invite.max_uses(max_uses);
+        }
+
+        if let Some(temporary) = self.temporary {
+            invite = invite.temporary(temporary);
+        }
+
+        if let Some(unique) = self.unique {
+            invite = invite.unique(unique);
+        }
+
+        match self.target_type {
+            None => {
+                if self.target_user_id.is_some() || self.target_application_id.is_some() {
+                    return Err(SerenityInviteConvertError::MissingTargetType);
+                }
+            }
+            Some(SerenityInviteTargetType::Stream) => {
+                if self.target_application_id.is_some() {
+                    return Err(SerenityInviteConvertError::MismatchedTargetId {
+                        field: "target_application_id",
+                    });
+                }
+                let Some(user_id) = self.target_user_id else {
+                    return Err(SerenityInviteConvertError::MissingTargetUserId);
+                };
+                invite = invite
+                    .target_type(SerenityInviteTargetType::Stream.into())
+                    .target_user_id(user_id);
+            }
+            Some(SerenityInviteTargetType::EmbeddedApplication) => {
+                if self.target_user_id.is_some() {
+                    return Err(SerenityInviteConvertError::MismatchedTargetId {
+                        field: "target_user_id",
+                    });
+                }
+                let Some(application_id) = self.target_application_id else {
+                    return Err(SerenityInviteConvertError::MissingTargetApplicationId);
+                };
+                invite = invite
+                    .target_type(SerenityInviteTargetType::EmbeddedApplication.into())
+                    .target_application_id(application_id);
+            }
+        }
+
+        Ok(invite)
+    }
+}
+
+impl_convert!(SerenityInvite => CreateInvite<'static>, SerenityInviteConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{ApplicationId, InviteTargetType, UserId};
+
+    use super::*;
+
+    static MOCK_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_invite_conversion() {
+        // serenity-builder
+        let mock_invite = SerenityInvite::builder()
+            .max_age(SerenityInviteMaxAge::Never)
+            .max_uses(100_u8)
+            .temporary(true)
+            .unique(true)
+            .target_type(SerenityInviteTargetType::Stream)
+            .target_user_id(UserId::new(MOCK_ID))
+            .build();
+        // serenity
+        let serenity_invite = CreateInvite::new()
+            .max_age(0)
+            .max_uses(100)
+            .temporary(true)
+            .unique(true)
+            .target_type(InviteTargetType::Stream)
+            .target_user_id(UserId::new(MOCK_ID));
+
+        let converted = mock_invite.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_invite).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invite_conversion_out_of_range() {
+        let too_long = SerenityInvite::builder()
+            .max_age(SerenityInviteMaxAge::Seconds(604801))
+            .build();
+        let too_many = SerenityInvite::builder().max_uses(101_u8).build();
+
+        assert!(matches!(
+            too_long.convert(),
+            Err(SerenityInviteConvertError::TooLongMaxAge {
+                actual: 604801,
+                limit: 604800
+            })
+        ));
+        assert!(matches!(
+            too_many.convert(),
+            Err(SerenityInviteConvertError::TooManyMaxUses {
+                actual: 101,
+                limit: 100
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invite_conversion_invalid_target() {
+        let without_type = SerenityInvite::builder()
+            .target_user_id(UserId::new(MOCK_ID))
+            .build();
+        let without_application = SerenityInvite::builder()
+            .target_type(SerenityInviteTargetType::EmbeddedApplication)
+            .build();
+        let mismatched = SerenityInvite::builder()
+            .target_type(SerenityInviteTargetType::Stream)
+            .target_user_id(UserId::new(MOCK_ID))
+            .target_application_id(ApplicationId::new(MOCK_ID))
+            .build();
+
+        assert!(matches!(
+            without_type.convert(),
+            Err(SerenityInviteConvertError::MissingTargetType)
+        ));
+        assert!(matches!(
+            without_application.convert(),
+            Err(SerenityInviteConvertError::MissingTargetApplicationId)
+        ));
+        assert!(matches!(
+            mismatched.convert(),
+            Err(SerenityInviteConvertError::MismatchedTargetId {
+                field: "target_application_id"
+            })
+        ));
+    }
+}
@@ -51,9 +51,65 @@
 #![deny(clippy::all)]
 #![allow(dead_code)]
 
+// Lets the code generated by the derive macros refer to `::serenity_builder` inside this crate too.
+extern crate self as serenity_builder;
+
+#[cfg(feature = "automod")]
+pub mod automod;
+#[cfg(feature = "channel")]
+pub mod channel;
+#[cfg(feature = "command")]
+pub mod command;
+#[cfg(feature = "diff")]
+pub mod diff;
 #[cfg(feature = "embed")]
 pub mod embed;
+#[cfg(feature = "emoji")]
+pub mod emoji;
+#[cfg(feature = "forum")]
+pub mod forum;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "invite")]
+pub mod invite;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+#[cfg(feature = "mention")]
+pub mod mention;
 #[cfg(feature = "message")]
 pub mod message;
+#[cfg(feature = "onboarding")]
+pub mod onboarding;
+#[cfg(feature = "patch")]
+pub mod patch;
+#[cfg(feature = "role")]
+pub mod role;
+#[cfg(feature = "scheduled_event")]
+pub mod scheduled_event;
+#[cfg(feature = "stage")]
+pub mod stage;
+#[cfg(feature = "sticker")]
+pub mod sticker;
+#[cfg(feature = "template")]
+pub mod template;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "theme")]
+pub mod theme;
+#[cfg(feature = "thread")]
+pub mod thread;
 
+pub mod color;
+pub mod convert;
+pub mod error;
 pub mod model;
+
+#[cfg(feature = "derive")]
+pub use serenity_builder_derive::{IntoSerenityEmbed, SerenityCommand, SerenityCommandChoice};
+
+/// Items used by the code generated by the derive macros. Not part of the public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serenity::all::{CommandData, CommandDataOptionValue, CommandOptionType, Timestamp};
+}
@@ -0,0 +1,357 @@
+use std::fmt;
+
+/// The size of a header in [SerenityMarkdown::header].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerenityMarkdownHeader {
+    /// `# Header`
+    Large,
+    /// `## Header`
+    Medium,
+    /// `### Header`
+    Small,
+}
+
+/// A builder for Discord-flavored Markdown.
+///
+/// Text passed as `&str` or `String` is escaped, so user input cannot break the formatting.
+/// Pass another [SerenityMarkdown] to nest formatting, or use [SerenityMarkdown::raw] for text that is already Markdown.
+///
+/// The result converts into a [String], so it can be passed directly to setters such as `SerenityEmbed::builder().description(...)` or `SerenityMessage::builder().content(...)`.
+///
+/// ```rs
+/// let description = SerenityMarkdown::new()
+///     .header(SerenityMarkdownHeader::Medium, "Results")
+///     .text("Winner: ")
+///     .bold(user_input)
+///     .newline()
+///     .bulleted_list(["First", "Second"])
+///     .code_block(Some("rs"), "let x = 1;")
+///     .masked_link("Details", "https://example.com");
+///
+/// let embed = SerenityEmbed::builder().description(description).build();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SerenityMarkdown {
+    text: String,
+}
+
+impl SerenityMarkdown {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append text that is already Markdown, without escaping it.
+    pub fn raw(mut self, markdown: &str) -> Self {
+        self.text.push_str(markdown);
+        self
+    }
+
+    /// Append plain text.
+    pub fn text(self, text: impl Into<SerenityMarkdown>) -> Self {
+        let text = text.into();
+        self.raw(&text.text)
+    }
+
+    /// Append `**bold**` text.
+    pub fn bold(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.wrap("**", text.into())
+    }
+
+    /// Append `*italic*` text.
+    pub fn italic(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.wrap("*", text.into())
+    }
+
+    /// Append `__underlined__` text.
+    pub fn underline(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.wrap("__", text.into())
+    }
+
+    /// Append `~~strikethrough~~` text.
+    pub fn strikethrough(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.wrap("~~", text.into())
+    }
+
+    /// Append `||spoiler||` text.
+    pub fn spoiler(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.wrap("||", text.into())
+    }
+
+    /// Append `` `inline code` ``. The code is shown as is, so it is not escaped.
+    pub fn inline_code(self, code: &str) -> Self {
+        if code.contains('`') {
+            // Double backticks allow single backticks inside. The spaces keep leading or trailing backticks apart.
+            self.raw(&format!("`` {} ``", code.replace("``", "`\u{200B}`")))
+        } else {
+            self.raw(&format!("`{code}`"))
+        }
+    }
+
+    /// Append a code block, optionally with a language for syntax highlighting. The code is shown as is, so it is not escaped.
+    pub fn code_block(self, language: Option<&str>, code: &str) -> Self {
+        // A zero-width space keeps ``` in the code from closing the block early.
+        let code = code.replace("```", "`\u{200B}``");
+        let language = language.unwrap_or_default();
+        let newline = if code.ends_with('\n') { "" } else { "\n" };
+        self.block(&format!("```{language}\n{code}{newline}```"))
+    }
+
+    /// Append a header on its own line.
+    pub fn header(self, size: SerenityMarkdownHeader, text: impl Into<SerenityMarkdown>) -> Self {
+        let prefix = match size {
+            SerenityMarkdownHeader::Large => "#",
+            SerenityMarkdownHeader::Medium => "##",
+            SerenityMarkdownHeader::Small => "###",
+        };
+        self.block(&format!("{prefix} {}", single_line(&text.into().text)))
+    }
+
+    /// Append `-# subtext` on its own line.
+    pub fn subtext(self, text: impl Into<SerenityMarkdown>) -> Self {
+        self.block(&format!("-# {}", single_line(&text.into().text)))
+    }
+
+    /// Append a block quote. Every line of the text is quoted.
+    pub fn quote(self, text: impl Into<SerenityMarkdown>) -> Self {
+        let quoted = text
+            .into()
+            .text
+            .lines()
+            .map(|line| format!("> {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.block(&quoted)
+    }
+
+    /// Append a bulleted list with one item per entry.
+    pub fn bulleted_list<T: Into<SerenityMarkdown>>(
+        self,
+        items: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let list = items
+            .into_iter()
+            .map(|item| format!("- {}", single_line(&item.into().text)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.block(&list)
+    }
+
+    /// Append a numbered list with one item per entry, starting at 1.
+    pub fn numbered_list<T: Into<SerenityMarkdown>>(
+        self,
+        items: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let list = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| format!("{}. {}", index + 1, single_line(&item.into().text)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.block(&list)
+    }
+
+    /// Append a masked link, shown as the text and opening the url when clicked.
+    ///
+    /// Masked links are only rendered in embeds and in messages sent by bots or webhooks.
+    pub fn masked_link(self, text: impl Into<SerenityMarkdown>, url: &str) -> Self {
+        // Encode the characters that would end the link target early.
+        let url = url
+            .replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29");
+        self.raw(&format!("[{}]({url})", text.into().text))
+    }
+
+    /// Start a new line.
+    pub fn newline(self) -> Self {
+        self.raw("\n")
+    }
+
+    /// The Markdown built so far.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Finish building and return the Markdown.
+    pub fn build(self) -> String {
+        self.text
+    }
+
+    fn wrap(self, marker: &str, text: SerenityMarkdown) -> Self {
+        if text.text.is_empty() {
+            return self;
+        }
+        self.raw(&format!("{marker}{}{marker}", text.text))
+    }
+
+    /// Append Markdown that must be on its own lines, such as headers and lists.
+    fn block(mut self, block: &str) -> Self {
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+        self.text.push_str(block);
+        self.text.push('\n');
+        self
+    }
+}
+
+/// Headers and list items end at the first line break, so join the lines with spaces.
+fn single_line(text: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(" ")
+}
+
+impl From<&str> for SerenityMarkdown {
+    fn from(text: &str) -> Self {
+        Self {
+            text: escape_markdown(text),
+        }
+    }
+}
+
+impl From<String> for SerenityMarkdown {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<&String> for SerenityMarkdown {
+    fn from(text: &String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<SerenityMarkdown> for String {
+    fn from(markdown: SerenityMarkdown) -> Self {
+        markdown.build()
+    }
+}
+
+impl fmt::Display for SerenityMarkdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Escape the characters Discord interprets as Markdown, so the text is shown as is.
+///
+/// ```rs
+/// assert_eq!(escape_markdown("**not bold**"), r"\*\*not bold\*\*");
+/// ```
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut line_start = true;
+    let mut leading_digits = false;
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '#' | '-' | '[' | ']'
+        ) || (c == '.' && leading_digits)
+        {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+
+        // `1.` at the start of a line begins a numbered list.
+        leading_digits = c.is_ascii_digit() && (line_start || leading_digits);
+        line_start = c == '\n';
+    }
+    escaped
+}
+
+/// Break the mentions in the text, so `@everyone`, `@here`, user and role mentions do not notify anyone.
+///
+/// A zero-width space is inserted after every `@`, so the text still looks the same.
+/// To control who is notified without changing the text, use [crate::model::message::SerenityMessageMentionType] instead.
+pub fn escape_mentions(text: &str) -> String {
+    text.replace('@', "@\u{200B}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MOCK_INPUT: &str = "*a* _b_ `c` [d](e)";
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown(MOCK_INPUT), r"\*a\* \_b\_ \`c\` \[d\](e)");
+        assert_eq!(
+            escape_markdown("1. first\n22. second"),
+            "1\\. first\n22\\. second"
+        );
+        assert_eq!(escape_markdown("version 1.0"), "version 1.0");
+        assert_eq!(escape_markdown("# title\n> quote"), "\\# title\n\\> quote");
+    }
+
+    #[test]
+    fn test_escape_mentions() {
+        let escaped = escape_mentions("@everyone <@123> <@&456>");
+        assert!(!escaped.contains("@everyone"));
+        assert!(!escaped.contains("<@123>"));
+        assert!(!escaped.contains("<@&456>"));
+        assert_eq!(escaped.replace('\u{200B}', ""), "@everyone <@123> <@&456>");
+    }
+
+    #[test]
+    fn test_markdown_builder() {
+        let markdown = SerenityMarkdown::new()
+            .header(SerenityMarkdownHeader::Medium, "Results")
+            .text("Winner: ")
+            .bold(SerenityMarkdown::new().italic(MOCK_INPUT))
+            .bulleted_list(["a", "b"])
+            .numbered_list(["c"])
+            .quote("line 1\nline 2")
+            .subtext("note")
+            .inline_code("x`y")
+            .spoiler("secret")
+            .newline()
+            .masked_link("Docs", "https://example.com/a (b)")
+            .code_block(Some("rs"), "let x = 1;")
+            .build();
+
+        assert_eq!(
+            markdown,
+            "## Results\n\
+             Winner: ***\\*a\\* \\_b\\_ \\`c\\` \\[d\\](e)***\n\
+             - a\n\
+             - b\n\
+             1. c\n\
+             > line 1\n\
+             > line 2\n\
+             -# note\n\
+             `` x`y ``||secret||\n\
+             [Docs](https://example.com/a%20%28b%29)\n\
+             ```rs\n\
+             let x = 1;\n\
+             ```\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_code_block_cannot_be_closed() {
+        let markdown = SerenityMarkdown::new()
+            .code_block(None, "```\n**bold**")
+            .build();
+
+        assert_eq!(markdown.matches("```").count(), 2);
+        assert!(markdown.contains("**bold**"));
+    }
+
+    #[cfg(all(feature = "embed", feature = "message"))]
+    #[test]
+    fn test_markdown_in_setters() {
+        use crate::model::embed::SerenityEmbed;
+        use crate::model::message::SerenityMessage;
+
+        let markdown = SerenityMarkdown::new().bold(MOCK_INPUT);
+
+        let embed = SerenityEmbed::builder()
+            .description(markdown.clone())
+            .build();
+        let message = SerenityMessage::builder().content(markdown.clone()).build();
+
+        assert_eq!(embed.description.as_deref(), Some(markdown.as_str()));
+        assert_eq!(message.content.as_deref(), Some(markdown.as_str()));
+    }
+}
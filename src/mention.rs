@@ -0,0 +1,394 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serenity::all::{ChannelId, CommandId, EmojiId, RoleId, Timestamp, UserId};
+
+/// How Discord displays a [SerenityMention::Timestamp]. The examples are shown in the reader's locale and time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerenityTimestampStyle {
+    /// `16:20` (`t`)
+    ShortTime,
+    /// `16:20:30` (`T`)
+    LongTime,
+    /// `20/04/2021` (`d`)
+    ShortDate,
+    /// `20 April 2021` (`D`)
+    LongDate,
+    /// `20 April 2021 16:20` (`f`). This is the default when no style is given.
+    ShortDateTime,
+    /// `Tuesday, 20 April 2021 16:20` (`F`)
+    LongDateTime,
+    /// `2 months ago` (`R`)
+    Relative,
+}
+
+impl SerenityTimestampStyle {
+    const FLAGS: [(char, SerenityTimestampStyle); 7] = [
+        ('t', Self::ShortTime),
+        ('T', Self::LongTime),
+        ('d', Self::ShortDate),
+        ('D', Self::LongDate),
+        ('f', Self::ShortDateTime),
+        ('F', Self::LongDateTime),
+        ('R', Self::Relative),
+    ];
+
+    /// The letter used in the timestamp token.
+    pub fn flag(self) -> char {
+        Self::FLAGS
+            .iter()
+            .find(|(_, style)| *style == self)
+            .map(|(flag, _)| *flag)
+            .unwrap_or('f')
+    }
+
+    fn from_flag(flag: &str) -> Option<Self> {
+        let mut chars = flag.chars();
+        let (Some(flag), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        Self::FLAGS
+            .iter()
+            .find(|(candidate, _)| *candidate == flag)
+            .map(|(_, style)| *style)
+    }
+}
+
+/// A mention or another special token that Discord renders in message content and embed text.
+///
+/// The [Display](fmt::Display) output is the token, and it converts into a [String], so it can be passed directly to setters such as `SerenityMessage::builder().content(...)`.
+///
+/// ```rs
+/// let content = format!(
+///     "{} won {}!",
+///     SerenityMention::user(user_id),
+///     SerenityMention::timestamp(finished_at, SerenityTimestampStyle::Relative),
+/// );
+///
+/// let mentions = SerenityMention::parse_all(&content); // [User(..), Timestamp { .. }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SerenityMention {
+    /// `<@id>`
+    User(UserId),
+    /// `<@&id>`
+    Role(RoleId),
+    /// `<#id>`
+    Channel(ChannelId),
+    /// `</name:id>`. The name may contain a subcommand, e.g. `config set`.
+    Command { name: String, id: CommandId },
+    /// `<:name:id>` or `<a:name:id>` for animated emojis.
+    Emoji {
+        name: String,
+        id: EmojiId,
+        animated: bool,
+    },
+    /// `<t:unix>` or `<t:unix:style>`
+    Timestamp {
+        unix: i64,
+        style: Option<SerenityTimestampStyle>,
+    },
+    /// `@everyone`
+    Everyone,
+    /// `@here`
+    Here,
+}
+
+impl SerenityMention {
+    /// Mention a user.
+    pub fn user(id: impl Into<UserId>) -> Self {
+        Self::User(id.into())
+    }
+
+    /// Mention a role.
+    pub fn role(id: impl Into<RoleId>) -> Self {
+        Self::Role(id.into())
+    }
+
+    /// Link a channel.
+    pub fn channel(id: impl Into<ChannelId>) -> Self {
+        Self::Channel(id.into())
+    }
+
+    /// Link a slash command, which the reader can click to use it.
+    pub fn command(name: impl Into<String>, id: impl Into<CommandId>) -> Self {
+        Self::Command {
+            name: name.into(),
+            id: id.into(),
+        }
+    }
+
+    /// Show a custom emoji.
+    pub fn emoji(name: impl Into<String>, id: impl Into<EmojiId>, animated: bool) -> Self {
+        Self::Emoji {
+            name: name.into(),
+            id: id.into(),
+            animated,
+        }
+    }
+
+    /// Show a timestamp in the reader's locale and time zone.
+    pub fn timestamp(timestamp: impl Into<Timestamp>, style: SerenityTimestampStyle) -> Self {
+        Self::Timestamp {
+            unix: timestamp.into().unix_timestamp(),
+            style: Some(style),
+        }
+    }
+
+    /// Whether Discord can notify someone because of this mention.
+    ///
+    /// Only user, role, `@everyone` and `@here` mentions notify, and only in message content.
+    pub fn pings(&self) -> bool {
+        matches!(
+            self,
+            Self::User(_) | Self::Role(_) | Self::Everyone | Self::Here
+        )
+    }
+
+    /// Find every mention in the text, in order of appearance.
+    ///
+    /// Tokens that are malformed, e.g. `<@abc>`, are ignored like Discord does.
+    pub fn parse_all(text: &str) -> Vec<SerenityMention> {
+        let mut mentions = Vec::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find(['<', '@']) {
+            let candidate = &rest[start..];
+
+            if candidate.starts_with('<')
+                && let Some(end) = candidate.find('>')
+                && let Ok(mention) = candidate[..=end].parse()
+            {
+                mentions.push(mention);
+                rest = &candidate[end + 1..];
+                continue;
+            }
+
+            if let Some(after) = candidate.strip_prefix("@everyone") {
+                mentions.push(Self::Everyone);
+                rest = after;
+            } else if let Some(after) = candidate.strip_prefix("@here") {
+                mentions.push(Self::Here);
+                rest = after;
+            } else {
+                rest = &candidate[1..];
+            }
+        }
+
+        mentions
+    }
+}
+
+/// Errors that can occur when parsing a single token with [SerenityMention::from_str].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityMentionParseError {
+    /// This occurs when the text is not a mention or another special token.
+    #[error("{actual:?} is not a mention.")]
+    NotAMention { actual: String },
+}
+
+impl FromStr for SerenityMention {
+    type Err = SerenityMentionParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "@everyone" => return Ok(Self::Everyone),
+            "@here" => return Ok(Self::Here),
+            _ => {}
+        }
+
+        let not_a_mention = || SerenityMentionParseError::NotAMention {
+            actual: text.to_string(),
+        };
+        let inner = text
+            .strip_prefix('<')
+            .and_then(|text| text.strip_suffix('>'))
+            .ok_or_else(not_a_mention)?;
+        let id = |id: &str| id.parse::<u64>().ok().filter(|id| *id != 0);
+
+        let mention = if let Some(role) = inner.strip_prefix("@&") {
+            id(role).map(|id| Self::Role(RoleId::new(id)))
+        } else if let Some(user) = inner.strip_prefix('@') {
+            // `<@!id>` is an older form of user mentions.
+            let user = user.strip_prefix('!').unwrap_or(user);
+            id(user).map(|id| Self::User(UserId::new(id)))
+        } else if let Some(channel) = inner.strip_prefix('#') {
+            id(channel).map(|id| Self::Channel(ChannelId::new(id)))
+        } else if let Some(command) = inner.strip_prefix('/') {
+            command
+                .rsplit_once(':')
+                .filter(|(name, _)| !name.is_empty())
+                .and_then(|(name, command_id)| {
+                    Some(Self::Command {
+                        name: name.to_string(),
+                        id: CommandId::new(id(command_id)?),
+                    })
+                })
+        } else if let Some(timestamp) = inner.strip_prefix("t:") {
+            let (unix, style) = match timestamp.split_once(':') {
+                Some((unix, flag)) => (unix, Some(SerenityTimestampStyle::from_flag(flag))),
+                None => (timestamp, None),
+            };
+            match (unix.parse::<i64>(), style) {
+                (Ok(unix), None) => Some(Self::Timestamp { unix, style: None }),
+                (Ok(unix), Some(Some(style))) => Some(Self::Timestamp {
+                    unix,
+                    style: Some(style),
+                }),
+                _ => None,
+            }
+        } else {
+            let (animated, emoji) = match inner.strip_prefix('a') {
+                Some(emoji) if emoji.starts_with(':') => (true, emoji),
+                _ => (false, inner),
+            };
+            emoji
+                .strip_prefix(':')
+                .and_then(|emoji| emoji.split_once(':'))
+                .filter(|(name, _)| !name.is_empty() && !name.contains(char::is_whitespace))
+                .and_then(|(name, emoji_id)| {
+                    Some(Self::Emoji {
+                        name: name.to_string(),
+                        id: EmojiId::new(id(emoji_id)?),
+                        animated,
+                    })
+                })
+        };
+
+        mention.ok_or_else(not_a_mention)
+    }
+}
+
+impl fmt::Display for SerenityMention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User(id) => write!(f, "<@{id}>"),
+            Self::Role(id) => write!(f, "<@&{id}>"),
+            Self::Channel(id) => write!(f, "<#{id}>"),
+            Self::Command { name, id } => write!(f, "</{name}:{id}>"),
+            Self::Emoji { name, id, animated } => {
+                let prefix = if *animated { "a" } else { "" };
+                write!(f, "<{prefix}:{name}:{id}>")
+            }
+            Self::Timestamp { unix, style } => match style {
+                Some(style) => write!(f, "<t:{unix}:{}>", style.flag()),
+                None => write!(f, "<t:{unix}>"),
+            },
+            Self::Everyone => f.write_str("@everyone"),
+            Self::Here => f.write_str("@here"),
+        }
+    }
+}
+
+impl From<SerenityMention> for String {
+    fn from(mention: SerenityMention) -> Self {
+        mention.to_string()
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl From<SerenityMention> for crate::markdown::SerenityMarkdown {
+    /// The token is inserted as is, so it is not escaped.
+    fn from(mention: SerenityMention) -> Self {
+        Self::new().raw(&mention.to_string())
+    }
+}
+
+#[cfg(feature = "message")]
+impl crate::model::message::SerenityMessage {
+    /// Find every mention in the content of the message, e.g. to compare it with `mention_type`.
+    pub fn content_mentions(&self) -> Vec<SerenityMention> {
+        self.content
+            .as_deref()
+            .map(SerenityMention::parse_all)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MOCK_TIMESTAMP_STR: &str = "2024-01-01T00:00:00Z";
+
+    #[test]
+    fn test_mention_format() {
+        let timestamp = Timestamp::parse(MOCK_TIMESTAMP_STR).unwrap();
+
+        assert_eq!(SerenityMention::user(UserId::new(1)).to_string(), "<@1>");
+        assert_eq!(SerenityMention::role(RoleId::new(2)).to_string(), "<@&2>");
+        assert_eq!(
+            SerenityMention::channel(ChannelId::new(3)).to_string(),
+            "<#3>"
+        );
+        assert_eq!(
+            SerenityMention::command("config set", CommandId::new(4)).to_string(),
+            "</config set:4>"
+        );
+        assert_eq!(
+            SerenityMention::emoji("party", EmojiId::new(5), true).to_string(),
+            "<a:party:5>"
+        );
+        assert_eq!(
+            SerenityMention::timestamp(timestamp, SerenityTimestampStyle::Relative).to_string(),
+            "<t:1704067200:R>"
+        );
+    }
+
+    #[test]
+    fn test_mention_parse_all() {
+        let mentions = SerenityMention::parse_all(
+            "<@1> <@!2> <@&3> <#4> </config set:5> <:ok:6> <a:party:7> <t:8> <t:-9:R> @everyone @here <@abc> <t:1:x> a@b",
+        );
+
+        assert_eq!(
+            mentions,
+            vec![
+                SerenityMention::User(UserId::new(1)),
+                SerenityMention::User(UserId::new(2)),
+                SerenityMention::Role(RoleId::new(3)),
+                SerenityMention::Channel(ChannelId::new(4)),
+                SerenityMention::command("config set", CommandId::new(5)),
+                SerenityMention::emoji("ok", EmojiId::new(6), false),
+                SerenityMention::emoji("party", EmojiId::new(7), true),
+                SerenityMention::Timestamp {
+                    unix: 8,
+                    style: None
+                },
+                SerenityMention::Timestamp {
+                    unix: -9,
+                    style: Some(SerenityTimestampStyle::Relative)
+                },
+                SerenityMention::Everyone,
+                SerenityMention::Here,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mention_round_trip() {
+        let mention = SerenityMention::emoji("ok", EmojiId::new(6), false);
+
+        assert_eq!(
+            mention.to_string().parse::<SerenityMention>().unwrap(),
+            mention
+        );
+        assert!("<@>".parse::<SerenityMention>().is_err());
+    }
+
+    #[cfg(feature = "message")]
+    #[test]
+    fn test_mention_in_message_content() {
+        use crate::model::message::SerenityMessage;
+
+        let message = SerenityMessage::builder()
+            .content(SerenityMention::role(RoleId::new(3)))
+            .build();
+
+        assert_eq!(message.content.as_deref(), Some("<@&3>"));
+        assert_eq!(
+            message.content_mentions(),
+            vec![SerenityMention::Role(RoleId::new(3))]
+        );
+    }
+}
@@ -1,7 +1,12 @@
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
 use crate::model::message::{SerenityMessage, SerenityMessageMentionType};
-use serenity::all::CreateMessage;
+use serenity::all::{CreateEmbed, CreateMessage};
 use serenity::builder::CreateAllowedMentions as Am;
 
+#[cfg(feature = "mention")]
+use crate::mention::SerenityMention;
+
 /// Errors that can occur when converting a custom message struct to a [serenity::all::CreateMessage].
 #[derive(thiserror::Error, Debug)]
 pub enum SerenityMessageConvertError {
@@ -11,14 +16,38 @@ pub enum SerenityMessageConvertError {
      * Serenity-builder will report an error during conversion.
      * Serenity does not return an error and leaves the response entirely up to the Discord API.
      */
-    #[error("The content exceeds the maximum length of 2000 characters.")]
-    TooLongContent,
+    #[error("The content is too long: {actual} characters (limit: {limit}).")]
+    TooLongContent { actual: usize, limit: usize },
     /**
      * This occurs when there is an error converting an embedded structure.
-     * The specific error details are encapsulated in the [crate::embed::SerenityEmbedConvertError].
+     * The index of the embed within the message and the specific error details, encapsulated in the [crate::embed::SerenityEmbedConvertError], are included.
+     * The message of the embed error is part of this message, so it is not reported again as the source.
      */
-    #[error(transparent)]
-    EmbedConvertError(#[from] crate::embed::SerenityEmbedConvertError),
+    #[error("Embed #{}: {error}", .index + 1)]
+    EmbedConvertError {
+        index: usize,
+        error: crate::embed::SerenityEmbedConvertError,
+    },
+}
+
+impl SerenityMessageConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongContent { .. } => ErrorKind::Length,
+            Self::EmbedConvertError { error, .. } => error.kind(),
+        }
+    }
+
+    /// The path of the offending value within the message, e.g. `embeds[1].description`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongContent { .. } => "content".to_string(),
+            Self::EmbedConvertError { index, error } => {
+                format!("embeds[{index}].{}", error.field_path())
+            }
+        }
+    }
 }
 
 impl SerenityMessage {
@@ -54,45 +83,24 @@ impl SerenityMessage {
     /// This function may return the following error:
     ///
     /// - [SerenityMessageConvertError::TooLongContent]: The content exceeds the maximum length of 2000 characters.
-    /// - [SerenityMessageConvertError::EmbedConvertError]: Failed to perform internal conversion for embed. (error [crate::embed::SerenityEmbedConvertError] reported by thiserror)
+    /// - [SerenityMessageConvertError::EmbedConvertError]: Failed to perform internal conversion for embed. (error [crate::embed::SerenityEmbedConvertError] with the index of the embed)
     pub fn convert(&self) -> Result<CreateMessage, SerenityMessageConvertError> {
         let mut message = serenity::builder::CreateMessage::default();
 
-        if let Some(content) = &self.content {
-            // Internal string data in the Discord API is handled in UTF-16 code units.
-            if content.encode_utf16().count() > 2000 {
-                return Err(SerenityMessageConvertError::TooLongContent);
-            }
+        if let Some(content) = self.validated_content()? {
             message = message.content(content);
         }
 
-        if let Some(embeds) = &self.embeds {
-            for embed in embeds {
-                let serenity_embed = embed.convert()?;
-                message = message.add_embed(serenity_embed);
-            }
+        for embed in self.convert_embeds()? {
+            message = message.add_embed(embed);
         }
 
-        if let Some(mention) = &self.mention_type {
-            match mention {
-                SerenityMessageMentionType::Everyone => {
-                    message = message.allowed_mentions(Am::new().everyone(true));
-                }
-                SerenityMessageMentionType::Here => {
-                    message = message.allowed_mentions(Am::new().all_users(true).all_roles(true));
-                }
-                SerenityMessageMentionType::Users(user_ids) => {
-                    message = message.allowed_mentions(Am::new().users(user_ids.clone()));
-                }
-                SerenityMessageMentionType::Roles(role_ids) => {
-                    message =
-                        message.allowed_mentions(Am::new().all_users(true).roles(role_ids.clone()));
-                }
-                SerenityMessageMentionType::Reply(ref_msg) => {
-                    message = message.reference_message(&**ref_msg);
-                    message = message.allowed_mentions(Am::new().replied_user(true));
-                }
-            }
+        if let Some(SerenityMessageMentionType::Reply(ref_msg)) = &self.mention_type {
+            message = message.reference_message(&**ref_msg);
+        }
+
+        if let Some(allowed_mentions) = self.allowed_mentions() {
+            message = message.allowed_mentions(allowed_mentions);
         }
 
         if let Some(sticker_ids) = &self.sticker_ids {
@@ -102,12 +110,244 @@ impl SerenityMessage {
         message = message.tts(self.tts);
         Ok(message)
     }
+
+    /// Return the content after checking its length.
+    pub(crate) fn validated_content(&self) -> Result<Option<&str>, SerenityMessageConvertError> {
+        let Some(content) = &self.content else {
+            return Ok(None);
+        };
+
+        // Internal string data in the Discord API is handled in UTF-16 code units.
+        let length = content.encode_utf16().count();
+        if length > 2000 {
+            return Err(SerenityMessageConvertError::TooLongContent {
+                actual: length,
+                limit: 2000,
+            });
+        }
+
+        Ok(Some(content))
+    }
+
+    /// Convert all embeds, reporting the index of the first one that fails.
+    pub(crate) fn convert_embeds(&self) -> Result<Vec<CreateEmbed>, SerenityMessageConvertError> {
+        let Some(embeds) = &self.embeds else {
+            return Ok(Vec::new());
+        };
+
+        embeds
+            .iter()
+            .enumerate()
+            .map(|(index, embed)| {
+                embed
+                    .convert()
+                    .map_err(|error| SerenityMessageConvertError::EmbedConvertError {
+                        index,
+                        error,
+                    })
+            })
+            .collect()
+    }
+
+    /// Map the mention type to the allowed mentions of the message.
+    pub(crate) fn allowed_mentions(&self) -> Option<Am> {
+        let allowed_mentions = match self.mention_type.as_ref()? {
+            SerenityMessageMentionType::Everyone => Am::new().everyone(true),
+            SerenityMessageMentionType::Here => Am::new().all_users(true).all_roles(true),
+            SerenityMessageMentionType::Users(user_ids) => Am::new().users(user_ids.clone()),
+            SerenityMessageMentionType::Roles(role_ids) => {
+                Am::new().all_users(true).roles(role_ids.clone())
+            }
+            SerenityMessageMentionType::Reply(_) => Am::new().replied_user(true),
+        };
+        Some(allowed_mentions)
+    }
+}
+
+/// Errors that can occur in [SerenityMessage::convert_strict].
+#[cfg(feature = "mention")]
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityStrictConvertError {
+    /// The message could not be converted. See [SerenityMessage::convert].
+    #[error(transparent)]
+    Convert(#[from] SerenityMessageConvertError),
+    /**
+     * This occurs when the mentions in the message do not match the mention type.
+     * See [SerenityMessage::check_mentions] for the checks that are done.
+     */
+    #[error(transparent)]
+    Mention(#[from] SerenityMentionWarning),
+}
+
+#[cfg(feature = "mention")]
+impl SerenityStrictConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Convert(e) => e.kind(),
+            Self::Mention(_) => ErrorKind::Conflict,
+        }
+    }
+
+    /// The path of the offending value within the message, e.g. `embeds[1].description`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::Convert(e) => e.field_path(),
+            Self::Mention(warning) => warning.field_path(),
+        }
+    }
 }
 
+/// A mismatch between the mentions in a message and its mention type, found by [SerenityMessage::check_mentions].
+#[cfg(feature = "mention")]
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum SerenityMentionWarning {
+    /// The content contains a mention that the mention type does not allow, so it will not notify anyone.
+    #[error(
+        "The content mentions {mention}, but the mention type does not allow it to notify anyone."
+    )]
+    Suppressed { mention: SerenityMention },
+    /// The content contains `@everyone` or `@here` and the mention type allows it, so it will notify many members.
+    /// This usually means that untrusted text was put into the content without escaping it, e.g. with `escape_mentions` of the `markdown` feature.
+    #[error("The content mentions {mention}, which will notify many members.")]
+    MassMention { mention: SerenityMention },
+    /// An embed contains a mention. Mentions in embeds are rendered, but never notify anyone.
+    #[error("Embed #{} mentions {mention}, but mentions in embeds never notify anyone.", .index + 1)]
+    InEmbed {
+        index: usize,
+        mention: SerenityMention,
+    },
+    /// The mention type allows a user or role that the content does not mention, so it will not be notified.
+    #[error("The mention type allows {mention}, but the content does not mention it.")]
+    NotInContent { mention: SerenityMention },
+}
+
+#[cfg(feature = "mention")]
+impl SerenityMentionWarning {
+    /// The path of the value the warning is about, e.g. `embeds[1]`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::Suppressed { .. } | Self::MassMention { .. } => "content".to_string(),
+            Self::InEmbed { index, .. } => format!("embeds[{index}]"),
+            Self::NotInContent { .. } => "mention_type".to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "mention")]
+impl SerenityMessage {
+    /// Compare the mentions in the content and embeds with the mention type, and report anything that will not behave as it looks.
+    ///
+    /// ```rs
+    /// let message = SerenityMessage::builder()
+    ///   .content("<@&123> please review")
+    ///   .mention_type(SerenityMessageMentionType::Users(vec![reviewer_id]))
+    ///   .build();
+    ///
+    /// for warning in message.check_mentions() {
+    ///     tracing::warn!("{warning}"); // The content mentions <@&123>, but the mention type does not allow it to notify anyone.
+    /// }
+    /// ```
+    ///
+    /// The following is reported:
+    ///
+    /// - [SerenityMentionWarning::Suppressed]: A user, role, `@everyone` or `@here` mention in the content that will not notify anyone.
+    /// - [SerenityMentionWarning::MassMention]: An `@everyone` or `@here` mention in the content that will notify many members.
+    /// - [SerenityMentionWarning::InEmbed]: A user, role, `@everyone` or `@here` mention in the description or a field of an embed.
+    /// - [SerenityMentionWarning::NotInContent]: A user or role in the mention type that the content does not mention.
+    pub fn check_mentions(&self) -> Vec<SerenityMentionWarning> {
+        let mut warnings = Vec::new();
+        let content_mentions = self.content_mentions();
+
+        for mention in content_mentions.iter().filter(|mention| mention.pings()) {
+            let mass = matches!(mention, SerenityMention::Everyone | SerenityMention::Here);
+            let mention = mention.clone();
+            if !self.allows_ping(&mention) {
+                warnings.push(SerenityMentionWarning::Suppressed { mention });
+            } else if mass {
+                warnings.push(SerenityMentionWarning::MassMention { mention });
+            }
+        }
+
+        for (index, embed) in self.embeds.iter().flatten().enumerate() {
+            let texts = embed
+                .description
+                .iter()
+                .chain(embed.fields.iter().flatten().map(|field| &field.value));
+            for mention in texts.flat_map(|text| SerenityMention::parse_all(text)) {
+                if mention.pings() {
+                    warnings.push(SerenityMentionWarning::InEmbed { index, mention });
+                }
+            }
+        }
+
+        let allowed = match &self.mention_type {
+            Some(SerenityMessageMentionType::Users(user_ids)) => user_ids
+                .iter()
+                .map(|id| SerenityMention::User(*id))
+                .collect(),
+            Some(SerenityMessageMentionType::Roles(role_ids)) => role_ids
+                .iter()
+                .map(|id| SerenityMention::Role(*id))
+                .collect(),
+            _ => Vec::new(),
+        };
+        for mention in allowed {
+            if !content_mentions.contains(&mention) {
+                warnings.push(SerenityMentionWarning::NotInContent { mention });
+            }
+        }
+
+        warnings
+    }
+
+    /// Convert the message like [SerenityMessage::convert], but fail if [SerenityMessage::check_mentions] reports anything.
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStrictConvertError::Mention]: The first warning reported by [SerenityMessage::check_mentions].
+    /// - [SerenityStrictConvertError::Convert]: The errors of [SerenityMessage::convert].
+    pub fn convert_strict(&self) -> Result<CreateMessage, SerenityStrictConvertError> {
+        if let Some(warning) = self.check_mentions().into_iter().next() {
+            return Err(warning.into());
+        }
+        Ok(self.convert()?)
+    }
+
+    /// Whether the mention in the content notifies anyone, following [SerenityMessage::allowed_mentions].
+    fn allows_ping(&self, mention: &SerenityMention) -> bool {
+        let Some(mention_type) = &self.mention_type else {
+            // Without allowed mentions, Discord notifies everything in the content.
+            return true;
+        };
+
+        match (mention_type, mention) {
+            (
+                SerenityMessageMentionType::Everyone,
+                SerenityMention::Everyone | SerenityMention::Here,
+            ) => true,
+            (
+                SerenityMessageMentionType::Here,
+                SerenityMention::User(_) | SerenityMention::Role(_),
+            ) => true,
+            (SerenityMessageMentionType::Users(user_ids), SerenityMention::User(id)) => {
+                user_ids.contains(id)
+            }
+            (SerenityMessageMentionType::Roles(_), SerenityMention::User(_)) => true,
+            (SerenityMessageMentionType::Roles(role_ids), SerenityMention::Role(id)) => {
+                role_ids.contains(id)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl_convert!(SerenityMessage => CreateMessage, SerenityMessageConvertError);
+
 #[cfg(test)]
 mod tests {
-    // TODO: Comparison Test with Serenity's CreateMessage.
-
     use serenity::all::StickerId;
 
     use super::*;
@@ -131,8 +371,21 @@ mod tests {
             .sticker_ids(vec![StickerId::new(MOCK_STICKER_ID)])
             .build();
 
+        // serenity
+        let serenity_message = CreateMessage::new()
+            .content(MOCK_TEST)
+            .add_embed(embed.convert().unwrap())
+            .allowed_mentions(Am::new().everyone(true))
+            .sticker_ids(vec![StickerId::new(MOCK_STICKER_ID)])
+            .tts(false);
+
         let converted = mock_message.convert();
+
         assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_message).unwrap()
+        );
     }
 
     #[test]
@@ -144,7 +397,10 @@ mod tests {
         assert!(converted.is_err());
         assert!(matches!(
             converted,
-            Err(SerenityMessageConvertError::TooLongContent)
+            Err(SerenityMessageConvertError::TooLongContent {
+                actual: 2001,
+                limit: 2000
+            })
         ));
     }
 
@@ -156,4 +412,91 @@ mod tests {
         let converted = mock_message.convert();
         assert!(converted.is_ok());
     }
+
+    #[test]
+    fn test_embed_error_index() {
+        let embeds = vec![
+            SerenityEmbed::builder().title("Test Embed").build(),
+            SerenityEmbed::builder()
+                .description("a".repeat(5000))
+                .build(),
+        ];
+        let mock_message = SerenityMessage::builder().embeds(embeds).build();
+
+        let error = mock_message.convert().unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityMessageConvertError::EmbedConvertError { index: 1, .. }
+        ));
+        assert_eq!(error.kind(), ErrorKind::Length);
+        assert_eq!(error.field_path(), "embeds[1].description");
+        assert_eq!(
+            error.to_string(),
+            "Embed #2: The description is too long: 5000 characters (limit: 4096)."
+        );
+        assert!(std::error::Error::source(&error).is_none());
+    }
+
+    #[cfg(feature = "mention")]
+    #[test]
+    fn test_check_mentions() {
+        use serenity::all::{RoleId, UserId};
+
+        use crate::mention::SerenityMention;
+        use crate::model::embed::SerenityEmbedField;
+
+        let role = SerenityMention::role(RoleId::new(1));
+        let user = SerenityMention::user(UserId::new(2));
+        let embed = SerenityEmbed::builder()
+            .fields(vec![
+                SerenityEmbedField::builder()
+                    .name("Reviewer")
+                    .value(user.to_string())
+                    .build(),
+            ])
+            .build();
+        let mock_message = SerenityMessage::builder()
+            .content(format!("{role} please review"))
+            .embeds(vec![embed])
+            .mention_type(SerenityMessageMentionType::Users(vec![UserId::new(2)]))
+            .build();
+
+        assert_eq!(
+            mock_message.check_mentions(),
+            vec![
+                SerenityMentionWarning::Suppressed {
+                    mention: role.clone()
+                },
+                SerenityMentionWarning::InEmbed {
+                    index: 0,
+                    mention: user.clone()
+                },
+                SerenityMentionWarning::NotInContent { mention: user },
+            ]
+        );
+
+        let error = mock_message.convert_strict().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Conflict);
+        assert_eq!(error.field_path(), "content");
+        assert_eq!(
+            error.to_string(),
+            "The content mentions <@&1>, but the mention type does not allow it to notify anyone."
+        );
+    }
+
+    #[cfg(feature = "mention")]
+    #[test]
+    fn test_check_mentions_mass_mention() {
+        let unrestricted = SerenityMessage::builder().content("@everyone look").build();
+        let restricted = SerenityMessage::builder()
+            .content("@\u{200B}everyone look")
+            .build();
+
+        assert!(matches!(
+            unrestricted.check_mentions().as_slice(),
+            [SerenityMentionWarning::MassMention { .. }]
+        ));
+        assert!(restricted.check_mentions().is_empty());
+        assert!(restricted.convert_strict().is_ok());
+    }
 }
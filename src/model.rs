@@ -1,4 +1,34 @@
+#[cfg(feature = "automod")]
+pub mod automod;
+#[cfg(feature = "channel")]
+pub mod channel;
+#[cfg(feature = "command")]
+pub mod command;
 #[cfg(feature = "embed")]
 pub mod embed;
+#[cfg(feature = "emoji")]
+pub mod emoji;
+#[cfg(feature = "forum")]
+pub mod forum;
+#[cfg(feature = "invite")]
+pub mod invite;
 #[cfg(feature = "message")]
 pub mod message;
+#[cfg(feature = "onboarding")]
+pub mod onboarding;
+#[cfg(feature = "patch")]
+pub mod patch;
+#[cfg(feature = "role")]
+pub mod role;
+#[cfg(feature = "scheduled_event")]
+pub mod scheduled_event;
+#[cfg(feature = "stage")]
+pub mod stage;
+#[cfg(feature = "sticker")]
+pub mod sticker;
+#[cfg(feature = "theme")]
+pub mod theme;
+#[cfg(feature = "thread")]
+pub mod thread;
+
+pub mod color;
@@ -0,0 +1,150 @@
+use serenity::all::{ChannelId, RoleId};
+use serenity::model::guild::automod::KeywordPresetType;
+
+/// The pre-defined word lists provided by Discord.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityAutoModKeywordPreset {
+    /// Words that may be considered forms of swearing or cursing.
+    Profanity,
+    /// Words that refer to sexually explicit behavior or activity.
+    SexualContent,
+    /// Personal insults or words that may be considered hate speech.
+    Slurs,
+}
+
+impl From<SerenityAutoModKeywordPreset> for KeywordPresetType {
+    fn from(value: SerenityAutoModKeywordPreset) -> Self {
+        match value {
+            SerenityAutoModKeywordPreset::Profanity => KeywordPresetType::Profanity,
+            SerenityAutoModKeywordPreset::SexualContent => KeywordPresetType::SexualContent,
+            SerenityAutoModKeywordPreset::Slurs => KeywordPresetType::Slurs,
+        }
+    }
+}
+
+/// The type of content which triggers an auto-moderation rule, together with its settings.
+///
+/// **Notes:** Serenity's [serenity::model::guild::automod::Trigger] cannot represent member profile rules, so rules with a [SerenityAutoModTrigger::MemberProfile] trigger are converted with [SerenityAutoModRule::convert_body].
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityAutoModTrigger {
+    /// Checks if content contains words or patterns from a user defined list.
+    Keyword {
+        /**
+         * Substrings which will be searched for in content. Wildcards (`*`) are supported.
+         *
+         * Due to Discord API limitations, a maximum of 1000 keywords of up to 60 characters each can be used.
+         */
+        keywords: Vec<String>,
+        /**
+         * Regular expression patterns which will be matched against content.
+         *
+         * Due to Discord API limitations, a maximum of 10 patterns of up to 260 characters each can be used.
+         */
+        regex_patterns: Vec<String>,
+        /**
+         * Substrings which should not trigger the rule.
+         *
+         * Due to Discord API limitations, a maximum of 100 entries of up to 60 characters each can be used.
+         */
+        allow_list: Vec<String>,
+    },
+    /// Checks if content represents generic spam.
+    Spam,
+    /// Checks if content contains words from Discord's pre-defined word lists.
+    KeywordPreset {
+        /// The pre-defined word lists to search for.
+        presets: Vec<SerenityAutoModKeywordPreset>,
+        /**
+         * Substrings which should not trigger the rule.
+         *
+         * Due to Discord API limitations, a maximum of 1000 entries of up to 60 characters each can be used.
+         */
+        allow_list: Vec<String>,
+    },
+    /// Checks if content contains more unique mentions than allowed.
+    MentionSpam {
+        /**
+         * The total number of unique role and user mentions allowed per message.
+         *
+         * Due to Discord API limitations, a maximum of 50 can be used.
+         */
+        mention_limit: u8,
+    },
+    /// Checks if the name or profile of a member contains words or patterns from a user defined list.
+    MemberProfile {
+        /**
+         * Substrings which will be searched for in member profiles. Wildcards (`*`) are supported.
+         *
+         * Due to Discord API limitations, a maximum of 1000 keywords of up to 60 characters each can be used.
+         */
+        keywords: Vec<String>,
+        /**
+         * Regular expression patterns which will be matched against member profiles.
+         *
+         * Due to Discord API limitations, a maximum of 10 patterns of up to 260 characters each can be used.
+         */
+        regex_patterns: Vec<String>,
+        /**
+         * Substrings which should not trigger the rule.
+         *
+         * Due to Discord API limitations, a maximum of 100 entries of up to 60 characters each can be used.
+         */
+        allow_list: Vec<String>,
+    },
+}
+
+/// An action taken when an auto-moderation rule is triggered.
+///
+/// | Action | Applicable triggers |
+/// | ------ | ------------------- |
+/// | `Block` | All except `MemberProfile` |
+/// | `Alert` | All |
+/// | `Timeout` | `Keyword`, `MentionSpam` |
+/// | `BlockMemberInteraction` | `MemberProfile` |
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityAutoModAction {
+    /// Blocks the message. The custom message (up to 150 characters) is shown to the member whose message was blocked.
+    Block { custom_message: Option<String> },
+    /// Logs the content to the given channel.
+    Alert(ChannelId),
+    /// Times out the member for the given number of seconds. (up to 2419200 seconds, 28 days)
+    Timeout(u32),
+    /// Prevents the member from using text, voice or other interactions until their profile is fixed.
+    BlockMemberInteraction,
+}
+
+/// A builder for creating or editing Discord auto-moderation rules using Serenity.
+/// This struct allows you to specify the trigger, the actions and the exemptions of the rule.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityAutoModRule {
+    /**
+     * The name of the rule.
+     *
+     * Due to Discord API limitations, the name must be between 1 and 100 characters. If it is not, [crate::automod::SerenityAutoModRuleConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The trigger of the rule.
+     *
+     * If a list exceeds the Discord API limitations described in [SerenityAutoModTrigger], [crate::automod::SerenityAutoModRuleConvertError::TooManyEntries] or [crate::automod::SerenityAutoModRuleConvertError::TooLongEntry] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub trigger: SerenityAutoModTrigger,
+    /**
+     * The actions taken when the rule is triggered. At least one action is required.
+     *
+     * If an action is not applicable to the trigger, [crate::automod::SerenityAutoModRuleConvertError::InapplicableAction] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub actions: Vec<SerenityAutoModAction>,
+    /// Whether the rule is enabled. (default: true)
+    #[builder(default = true, setter(into))]
+    pub enabled: bool,
+    /// The roles that are not affected by the rule. (up to 20 roles)
+    #[builder(default, setter(strip_option, into))]
+    pub exempt_roles: Option<Vec<RoleId>>,
+    /// The channels that are not affected by the rule. (up to 50 channels)
+    #[builder(default, setter(strip_option, into))]
+    pub exempt_channels: Option<Vec<ChannelId>>,
+}
@@ -0,0 +1,218 @@
+use serenity::all::{
+    ChannelId, ChannelType, ForumLayoutType, PermissionOverwrite, PermissionOverwriteType,
+    Permissions, RoleId, SortOrder, UserId, VideoQualityMode,
+};
+
+use crate::model::forum::SerenityForumTagEmoji;
+
+/// The type of guild channel to create or edit.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SerenityChannelType {
+    /// A text channel.
+    Text,
+    /// A voice channel.
+    Voice,
+    /// A stage channel.
+    Stage,
+    /// A category that can contain other channels.
+    Category,
+    /// An announcement (news) channel.
+    Announcement,
+    /// A forum channel.
+    Forum,
+}
+
+impl From<SerenityChannelType> for ChannelType {
+    fn from(value: SerenityChannelType) -> Self {
+        match value {
+            SerenityChannelType::Text => ChannelType::Text,
+            SerenityChannelType::Voice => ChannelType::Voice,
+            SerenityChannelType::Stage => ChannelType::Stage,
+            SerenityChannelType::Category => ChannelType::Category,
+            SerenityChannelType::Announcement => ChannelType::News,
+            SerenityChannelType::Forum => ChannelType::Forum,
+        }
+    }
+}
+
+/// The target of a [SerenityPermissionOverwrite].
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityPermissionOverwriteTarget {
+    /// Overwrites the permissions of a role.
+    Role(RoleId),
+    /// Overwrites the permissions of a single member.
+    Member(UserId),
+}
+
+/// A channel-specific permission overwrite for a role or member.
+///
+/// Permissions that are neither allowed nor denied are inherited from the guild (or the parent category).
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityPermissionOverwrite {
+    /// The role or member to which the overwrite applies.
+    #[builder(setter(into))]
+    pub target: SerenityPermissionOverwriteTarget,
+    /// The permissions explicitly allowed. (default: none)
+    #[builder(default = Permissions::empty(), setter(into))]
+    pub allow: Permissions,
+    /// The permissions explicitly denied. (default: none)
+    #[builder(default = Permissions::empty(), setter(into))]
+    pub deny: Permissions,
+}
+
+impl From<&SerenityPermissionOverwrite> for PermissionOverwrite {
+    fn from(value: &SerenityPermissionOverwrite) -> Self {
+        let kind = match value.target {
+            SerenityPermissionOverwriteTarget::Role(id) => PermissionOverwriteType::Role(id),
+            SerenityPermissionOverwriteTarget::Member(id) => PermissionOverwriteType::Member(id),
+        };
+
+        PermissionOverwrite {
+            allow: value.allow,
+            deny: value.deny,
+            kind,
+        }
+    }
+}
+
+/// The default order in which posts are sorted in a forum channel.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityForumSortOrder {
+    /// Sort posts by recent activity.
+    LatestActivity,
+    /// Sort posts by creation time, from most recent to oldest.
+    CreationDate,
+}
+
+impl From<SerenityForumSortOrder> for SortOrder {
+    fn from(value: SerenityForumSortOrder) -> Self {
+        match value {
+            SerenityForumSortOrder::LatestActivity => SortOrder::LatestActivity,
+            SerenityForumSortOrder::CreationDate => SortOrder::CreationDate,
+        }
+    }
+}
+
+/// The default layout in which posts are displayed in a forum channel.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityForumLayout {
+    /// Display posts as a list.
+    List,
+    /// Display posts as a collection of tiles.
+    Gallery,
+}
+
+impl From<SerenityForumLayout> for ForumLayoutType {
+    fn from(value: SerenityForumLayout) -> Self {
+        match value {
+            SerenityForumLayout::List => ForumLayoutType::ListView,
+            SerenityForumLayout::Gallery => ForumLayoutType::GalleryView,
+        }
+    }
+}
+
+/// The camera video quality of a voice or stage channel.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityVideoQuality {
+    /// Discord chooses the quality for optimal performance.
+    Auto,
+    /// 720p.
+    Full,
+}
+
+impl From<SerenityVideoQuality> for VideoQualityMode {
+    fn from(value: SerenityVideoQuality) -> Self {
+        match value {
+            SerenityVideoQuality::Auto => VideoQualityMode::Auto,
+            SerenityVideoQuality::Full => VideoQualityMode::Full,
+        }
+    }
+}
+
+/// A builder for creating or editing Discord guild channels using Serenity.
+/// This struct covers text, voice, stage, category, announcement and forum channels.
+///
+/// Not every value is applicable to every channel type. If a value is set for a channel type that does not support it, [crate::channel::SerenityChannelConvertError::InapplicableField] will be returned during conversion.
+///
+/// | Value | Applicable channel types |
+/// | ----- | ------------------------ |
+/// | `topic` | Text, Announcement, Forum |
+/// | `nsfw` | Text, Voice, Stage, Announcement, Forum |
+/// | `slowmode` | Text, Voice, Stage, Forum |
+/// | `bitrate`, `user_limit`, `rtc_region`, `video_quality` | Voice, Stage |
+/// | `parent_id` | All except Category |
+/// | `default_reaction`, `default_sort_order`, `default_forum_layout` | Forum |
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityChannel {
+    /**
+     * The name of the channel.
+     *
+     * Due to Discord API limitations, the name must be between 1 and 100 characters. If it is not, [crate::channel::SerenityChannelConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /// The type of the channel. (default: [SerenityChannelType::Text])
+    #[builder(default = SerenityChannelType::Text, setter(into))]
+    pub kind: SerenityChannelType,
+    /**
+     * The topic of the channel.
+     *
+     * Due to Discord API limitations, a maximum of 1024 characters can be used. If the character count exceeds this limit, [crate::channel::SerenityChannelConvertError::TooLongTopic] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub topic: Option<String>,
+    /// The sorting position of the channel.
+    #[builder(default, setter(strip_option, into))]
+    pub position: Option<u16>,
+    /// Whether the channel is age-restricted.
+    #[builder(default, setter(strip_option, into))]
+    pub nsfw: Option<bool>,
+    /**
+     * The slowmode of the channel in seconds.
+     *
+     * Due to Discord API limitations, a maximum of 21600 seconds (6 hours) can be used. If this limit is exceeded, [crate::channel::SerenityChannelConvertError::TooLongSlowmode] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub slowmode: Option<u16>,
+    /**
+     * The bitrate of the voice or stage channel in bits per second.
+     *
+     * Due to Discord API limitations, the bitrate must be between 8000 and 384000. If it is not, [crate::channel::SerenityChannelConvertError::InvalidBitrate] will be returned during conversion.
+     * Values above 96000 additionally require a boosted guild, which is left to the Discord API.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub bitrate: Option<u32>,
+    /**
+     * The maximum number of users allowed in the voice or stage channel. (`0` means unlimited)
+     *
+     * Due to Discord API limitations, a maximum of 99 can be used for voice channels and 10000 for stage channels. If this limit is exceeded, [crate::channel::SerenityChannelConvertError::TooManyUserLimit] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub user_limit: Option<u32>,
+    /// The ID of the parent category.
+    #[builder(default, setter(strip_option, into))]
+    pub parent_id: Option<ChannelId>,
+    /// The emoji shown in the add reaction button on posts in the forum channel.
+    #[builder(default, setter(strip_option, into))]
+    pub default_reaction: Option<SerenityForumTagEmoji>,
+    /// The default order in which posts are sorted in the forum channel.
+    #[builder(default, setter(strip_option, into))]
+    pub default_sort_order: Option<SerenityForumSortOrder>,
+    /**
+     * The default layout in which posts are displayed in the forum channel.
+     *
+     * Serenity's [serenity::all::CreateChannel] cannot set this value, so setting it for [SerenityChannel::convert] will result in [crate::channel::SerenityChannelConvertError::ForumLayoutOnCreate] being returned.
+     * Use [SerenityChannel::convert_body] to create a channel with it.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub default_forum_layout: Option<SerenityForumLayout>,
+    /// The voice region of the voice or stage channel. Automatic when unset.
+    #[builder(default, setter(strip_option, into))]
+    pub rtc_region: Option<String>,
+    /// The camera video quality of the voice or stage channel.
+    #[builder(default, setter(strip_option, into))]
+    pub video_quality: Option<SerenityVideoQuality>,
+    /// The permission overwrites of the channel.
+    #[builder(default, setter(strip_option, into))]
+    pub permission_overwrites: Option<Vec<SerenityPermissionOverwrite>>,
+}
@@ -0,0 +1,115 @@
+use serenity::all::Colour;
+
+/// A 24-bit RGB color, used for [crate::model::embed::SerenityEmbed::color].
+///
+/// A color can be created from a color code (`0x5865F2_u32`), an RGB tuple (`(88, 101, 242)`), Serenity's [Colour], [SerenityColor::from_hsl],
+/// or parsed from `#RRGGBB`, `RRGGBB` and the names of the Discord brand colors, e.g. `"blurple"`.
+///
+/// In JSON, TOML and other configs, both the numeric and the string forms are accepted:
+///
+/// ```json
+/// { "color": "#5865F2" }
+/// { "color": 5793266 }
+/// ```
+///
+/// Color codes that do not fit in 24 bits are accepted here, like with the builder, and reported during the conversion of the embed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SerenityColor(pub(crate) u32);
+
+impl SerenityColor {
+    /// Discord's brand blurple. (`#5865F2`)
+    pub const BLURPLE: Self = Self(0x5865F2);
+    /// Discord's brand green. (`#57F287`)
+    pub const GREEN: Self = Self(0x57F287);
+    /// Discord's brand yellow. (`#FEE75C`)
+    pub const YELLOW: Self = Self(0xFEE75C);
+    /// Discord's brand fuchsia. (`#EB459E`)
+    pub const FUCHSIA: Self = Self(0xEB459E);
+    /// Discord's brand red. (`#ED4245`)
+    pub const RED: Self = Self(0xED4245);
+    /// Discord's brand white. (`#FFFFFF`)
+    pub const WHITE: Self = Self(0xFFFFFF);
+    /// Discord's brand black. (`#23272A`)
+    pub const BLACK: Self = Self(0x23272A);
+
+    /// The largest color code that fits in 24 bits.
+    pub const MAX: u32 = 0xFFFFFF;
+
+    /// The brand colors by the names accepted when parsing.
+    pub(crate) const NAMED: [(&'static str, SerenityColor); 7] = [
+        ("blurple", Self::BLURPLE),
+        ("green", Self::GREEN),
+        ("yellow", Self::YELLOW),
+        ("fuchsia", Self::FUCHSIA),
+        ("red", Self::RED),
+        ("white", Self::WHITE),
+        ("black", Self::BLACK),
+    ];
+
+    /// The color code, e.g. `0x5865F2`.
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for SerenityColor {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<(u8, u8, u8)> for SerenityColor {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::from_rgb(r, g, b)
+    }
+}
+
+impl From<Colour> for SerenityColor {
+    fn from(colour: Colour) -> Self {
+        Self(colour.0)
+    }
+}
+
+impl From<SerenityColor> for Colour {
+    fn from(color: SerenityColor) -> Self {
+        Colour(color.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SerenityColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = SerenityColor;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a color code, `#RRGGBB`, `RRGGBB` or a Discord color name")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                u32::try_from(value)
+                    .map(SerenityColor)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl serde::Serialize for SerenityColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
@@ -0,0 +1,162 @@
+use serenity::all::CommandOptionType;
+
+/// A builder for creating Discord slash commands (chat input commands).
+///
+/// Subcommands and subcommand groups are not supported yet.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq)]
+pub struct SerenityCommand {
+    /**
+     * The name of the command.
+     *
+     * The name must be between 1 and 32 characters and may only contain lowercase letters, digits, `-`, `_` and `'`. If it does not, [crate::command::SerenityCommandConvertError::InvalidName] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The description of the command.
+     *
+     * The description must be between 1 and 100 characters. If it is not, [crate::command::SerenityCommandConvertError::InvalidDescriptionLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub description: String,
+    /**
+     * The options of the command. (up to 25 options)
+     *
+     * Any additional options will result in a [crate::command::SerenityCommandConvertError::TooManyOptions] being returned during conversion.
+     * Required options must come before optional ones.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub options: Option<Vec<SerenityCommandOption>>,
+}
+
+/// Option structures used in [SerenityCommand].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq)]
+pub struct SerenityCommandOption {
+    /// The type of the option. Subcommands and subcommand groups are not supported.
+    pub kind: CommandOptionType,
+    /**
+     * The name of the option.
+     *
+     * The same rules as for the name of the command apply. If the name is invalid, [crate::command::SerenityCommandConvertError::InvalidOptionName] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The description of the option.
+     *
+     * The description must be between 1 and 100 characters. If it is not, [crate::command::SerenityCommandConvertError::InvalidOptionDescriptionLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub description: String,
+    /// Whether the option must be filled in. (default: false)
+    #[builder(default = false, setter(into))]
+    pub required: bool,
+    /**
+     * The predefined values the user can pick from. (up to 25 choices)
+     *
+     * Choices can only be used with string, integer and number options, and their values must match the type of the option.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub choices: Option<Vec<SerenityCommandChoice>>,
+    /**
+     * The minimum value of an integer or number option.
+     *
+     * Serenity only accepts non-negative limits for integer options. A negative one will result in [crate::command::SerenityCommandConvertError::NegativeIntegerValue] being returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub min_value: Option<SerenityCommandOptionLimit>,
+    /**
+     * The maximum value of an integer or number option.
+     *
+     * Serenity only accepts non-negative limits for integer options. A negative one will result in [crate::command::SerenityCommandConvertError::NegativeIntegerValue] being returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub max_value: Option<SerenityCommandOptionLimit>,
+    /// The minimum length of a string option. (up to 6000)
+    #[builder(default, setter(strip_option, into))]
+    pub min_length: Option<u16>,
+    /// The maximum length of a string option. (1 to 6000)
+    #[builder(default, setter(strip_option, into))]
+    pub max_length: Option<u16>,
+}
+
+/// Choice structures used in [SerenityCommandOption].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq)]
+pub struct SerenityCommandChoice {
+    /**
+     * The name of the choice shown to the user.
+     *
+     * The name must be between 1 and 100 characters. If it is not, [crate::command::SerenityCommandConvertError::InvalidChoiceNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /// The value sent to the bot when the choice is picked.
+    #[builder(setter(into))]
+    pub value: SerenityCommandChoiceValue,
+}
+
+/// The value of a [SerenityCommandChoice]. It must match the type of the option.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum SerenityCommandChoiceValue {
+    /// A value of a string option.
+    String(String),
+    /// A value of an integer option. Serenity only supports 32-bit integer choices.
+    Integer(i32),
+    /// A value of a number option.
+    Number(f64),
+}
+
+impl From<&str> for SerenityCommandChoiceValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for SerenityCommandChoiceValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i32> for SerenityCommandChoiceValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for SerenityCommandChoiceValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+/// The minimum or maximum value of a [SerenityCommandOption].
+///
+/// Integers are kept as they are, so large limits of integer options are not rounded.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum SerenityCommandOptionLimit {
+    /// An integer limit.
+    Integer(i64),
+    /// A decimal limit. Integer options only accept decimals without a fractional part.
+    Number(f64),
+}
+
+impl From<i32> for SerenityCommandOptionLimit {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<i64> for SerenityCommandOptionLimit {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for SerenityCommandOptionLimit {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::model::color::SerenityColor;
+
 /// A builder for creating Discord embeds.
 /// This struct allows you to build rich embed messages for Discord using a fluent interface.
 ///
@@ -18,7 +20,9 @@
 /// - `kind`: The type of embed. Discord currently only supports "rich" embeds, so this field is unnecessary.
 /// - `video`: `rich` embeds do not support video content, so this field is unnecessary.
 /// - `provider`: `rich` embeds do not support provider information, so this field is unnecessary.
-#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone)]
+#[derive(
+    serde::Deserialize, typed_builder::TypedBuilder, Clone, Default, Debug, PartialEq, Eq, Hash,
+)]
 pub struct SerenityEmbed {
     /// The title of the embed.
     #[builder(default, setter(strip_option, into))]
@@ -42,11 +46,13 @@ pub struct SerenityEmbed {
     /**
      * The color of the embed.
      *
-     * Serenity uses [serenity::model::colour::Colour], but Builder only allows direct specification from color codes.
-     * e.g. `0xff0000` for red.
+     * Accepts anything that converts into a [SerenityColor], e.g. `0xff0000_u32`, `(255, 0, 0)`, [serenity::model::colour::Colour] or [SerenityColor::RED].
+     * Hex strings such as `"#ff0000"` can be parsed with [str::parse].
+     *
+     * If the color code does not fit in 24 bits, [crate::embed::SerenityEmbedConvertError::InvalidColor] will be returned during conversion.
      */
     #[builder(default, setter(strip_option, into))]
-    pub color: Option<u32>,
+    pub color: Option<SerenityColor>,
     /// The footer of the embed.
     #[builder(default, setter(strip_option, into))]
     pub footer_text: Option<String>,
@@ -79,7 +85,7 @@ pub struct SerenityEmbed {
 
 /// Field structures used in [SerenityEmbed].
 /// These structures can be used as Vec (arrays) in [SerenityEmbed] and are internally converted to be handled by [serenity::model::channel::Embed].
-#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone)]
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SerenityEmbedField {
     /// The name of the field.
     #[builder(setter(into))]
@@ -88,6 +94,7 @@ pub struct SerenityEmbedField {
     #[builder(setter(into))]
     pub value: String,
     /// Whether the field is displayed inline. (default: false)
+    #[serde(default)]
     #[builder(default = false, setter(into))]
     pub inline: bool,
 }
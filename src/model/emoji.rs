@@ -0,0 +1,24 @@
+use serenity::all::RoleId;
+
+/// A builder for creating Discord guild emojis using Serenity.
+/// This struct allows you to specify the name, image and role restrictions of the emoji.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityEmoji {
+    /**
+     * The name of the emoji.
+     *
+     * Due to Discord API limitations, the name must be 2 to 32 characters long and only contain alphanumeric characters and underscores. If it is not, [crate::emoji::SerenityEmojiConvertError::InvalidName] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The image of the emoji as raw image data.
+     *
+     * Due to Discord API limitations, a maximum of 256 KB can be used. If the image exceeds this limit, [crate::emoji::SerenityEmojiConvertError::TooLargeImage] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub image: Vec<u8>,
+    /// The roles allowed to use the emoji. Everyone can use it when unset.
+    #[builder(default, setter(strip_option, into))]
+    pub roles: Option<Vec<RoleId>>,
+}
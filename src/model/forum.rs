@@ -0,0 +1,69 @@
+use serenity::all::{EmojiId, ForumTagId};
+
+use crate::model::message::SerenityMessage;
+use crate::model::thread::SerenityThreadAutoArchiveDuration;
+
+/// A builder for creating Discord forum posts using Serenity.
+/// This struct allows you to specify the title, the starter message and the tags applied to the post.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityForumPost {
+    /**
+     * The title of the post.
+     *
+     * Due to Discord API limitations, the title must be between 1 and 100 characters. If it is not, [crate::forum::SerenityForumPostConvertError::InvalidTitleLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub title: String,
+    /**
+     * The starter message of the post.
+     *
+     * It is validated in the same way as [crate::model::message::SerenityMessage::convert].
+     */
+    #[builder(setter(into))]
+    pub message: SerenityMessage,
+    /**
+     * The IDs of the tags applied to the post. (up to 5 tags)
+     *
+     * Due to Discord API limitations, only 5 tags can be applied. Any additional tags will result in a [crate::forum::SerenityForumPostConvertError::TooManyAppliedTags] being returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub applied_tags: Option<Vec<ForumTagId>>,
+    /// The duration after which the post is automatically archived.
+    #[builder(default, setter(strip_option, into))]
+    pub auto_archive_duration: Option<SerenityThreadAutoArchiveDuration>,
+    /**
+     * The slowmode of the post in seconds.
+     *
+     * Due to Discord API limitations, a maximum of 21600 seconds (6 hours) can be used. If this limit is exceeded, [crate::forum::SerenityForumPostConvertError::TooLongSlowmode] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub slowmode: Option<u16>,
+}
+
+/// The emoji displayed next to a forum tag.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityForumTagEmoji {
+    /// A custom guild emoji, specified by its ID.
+    Custom(EmojiId),
+    /// A unicode emoji. e.g. `"👍"`
+    Unicode(String),
+}
+
+/// A builder for creating Discord forum tags using Serenity.
+/// The converted tags can be passed to [serenity::builder::EditChannel::available_tags].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityForumTag {
+    /**
+     * The name of the tag.
+     *
+     * Due to Discord API limitations, a maximum of 20 characters can be used. If the character count exceeds this limit, [crate::forum::SerenityForumTagConvertError::TooLongName] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /// Whether the tag can only be added to or removed from posts by moderators. (default: false)
+    #[builder(default = false, setter(into))]
+    pub moderated: bool,
+    /// The emoji of the tag.
+    #[builder(default, setter(strip_option, into))]
+    pub emoji: Option<SerenityForumTagEmoji>,
+}
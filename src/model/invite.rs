@@ -0,0 +1,65 @@
+use serenity::all::{ApplicationId, InviteTargetType, UserId};
+
+/// How long an invite is valid for.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityInviteMaxAge {
+    /// The invite never expires.
+    Never,
+    /**
+     * The invite expires after the given number of seconds.
+     *
+     * Due to Discord API limitations, a maximum of 604800 seconds (7 days) can be used. If this limit is exceeded, [crate::invite::SerenityInviteConvertError::TooLongMaxAge] will be returned during conversion.
+     */
+    Seconds(u32),
+}
+
+/// The type of target of a voice channel invite.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityInviteTargetType {
+    /// Invites to watch a user's stream. `target_user_id` is required.
+    Stream,
+    /// Invites to an embedded application (activity). `target_application_id` is required.
+    EmbeddedApplication,
+}
+
+impl From<SerenityInviteTargetType> for InviteTargetType {
+    fn from(value: SerenityInviteTargetType) -> Self {
+        match value {
+            SerenityInviteTargetType::Stream => InviteTargetType::Stream,
+            SerenityInviteTargetType::EmbeddedApplication => InviteTargetType::EmbeddedApplication,
+        }
+    }
+}
+
+/// A builder for creating Discord channel invites using Serenity.
+/// This struct allows you to specify the lifetime, usage limit and target of the invite.
+///
+/// All values are optional. Values that are not set are left to the Discord API defaults. (24 hours, unlimited uses)
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityInvite {
+    /// How long the invite is valid for.
+    #[builder(default, setter(strip_option, into))]
+    pub max_age: Option<SerenityInviteMaxAge>,
+    /**
+     * The maximum number of times the invite can be used. (`0` means unlimited)
+     *
+     * Due to Discord API limitations, a maximum of 100 can be used. If this limit is exceeded, [crate::invite::SerenityInviteConvertError::TooManyMaxUses] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub max_uses: Option<u8>,
+    /// Whether the invite only grants temporary membership.
+    #[builder(default, setter(strip_option, into))]
+    pub temporary: Option<bool>,
+    /// Whether to always create a new invite instead of reusing a similar one.
+    #[builder(default, setter(strip_option, into))]
+    pub unique: Option<bool>,
+    /// The type of target of the invite. (voice channels only)
+    #[builder(default, setter(strip_option, into))]
+    pub target_type: Option<SerenityInviteTargetType>,
+    /// The ID of the user whose stream to display. Only used with [SerenityInviteTargetType::Stream].
+    #[builder(default, setter(strip_option, into))]
+    pub target_user_id: Option<UserId>,
+    /// The ID of the embedded application to open. Only used with [SerenityInviteTargetType::EmbeddedApplication].
+    #[builder(default, setter(strip_option, into))]
+    pub target_application_id: Option<ApplicationId>,
+}
@@ -3,7 +3,9 @@ use serenity::all::{Message, RoleId, StickerId, UserId};
 
 /// An enumeration representing the different types of mentions that can be included in a Discord message.
 /// These mention types allow you to specify who or what should be mentioned in the message.
-#[derive(serde::Deserialize, Clone)]
+///
+/// Serenity's [Message] cannot be compared, so two [SerenityMessageMentionType::Reply] are equal when they reply to the same message (by channel and message id).
+#[derive(serde::Deserialize, Clone, Debug)]
 pub enum SerenityMessageMentionType {
     /// Mentions everyone in the guild.
     Everyone,
@@ -17,12 +19,38 @@ pub enum SerenityMessageMentionType {
     Reply(Box<Message>), // Boxed to reduce size.
 }
 
+impl PartialEq for SerenityMessageMentionType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Everyone, Self::Everyone) | (Self::Here, Self::Here) => true,
+            (Self::Users(a), Self::Users(b)) => a == b,
+            (Self::Roles(a), Self::Roles(b)) => a == b,
+            (Self::Reply(a), Self::Reply(b)) => (a.channel_id, a.id) == (b.channel_id, b.id),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SerenityMessageMentionType {}
+
+impl std::hash::Hash for SerenityMessageMentionType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Everyone | Self::Here => {}
+            Self::Users(user_ids) => user_ids.hash(state),
+            Self::Roles(role_ids) => role_ids.hash(state),
+            Self::Reply(message) => (message.channel_id, message.id).hash(state),
+        }
+    }
+}
+
 /// A builder for creating Discord messages using Serenity.
 /// This struct allows you to specify various parameters for the message.
 /// such as content, embeds, text-to-speech (TTS) settings, mention types, and sticker IDs.
 ///
 /// **Notes:** At the time of the v0.3.0 release, serenity-builder can only generate messages consisting of text strings, embeds, and stickers.
-#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone)]
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SerenityMessage {
     /**
      * The content of the message.
@@ -0,0 +1,128 @@
+use serenity::all::{ChannelId, EmojiId, RoleId};
+
+/// The emoji displayed next to a welcome channel or an onboarding option.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityGuildEmoji {
+    /// A custom guild emoji, specified by its ID and name.
+    Custom { id: EmojiId, name: String },
+    /// A unicode emoji. e.g. `"👋"`
+    Unicode(String),
+}
+
+/// A builder for editing the welcome screen of a Discord guild using Serenity.
+/// The welcome screen is shown to new members of community guilds.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityWelcomeScreen {
+    /// Whether the welcome screen is enabled.
+    #[builder(default, setter(strip_option, into))]
+    pub enabled: Option<bool>,
+    /**
+     * The guild description shown in the welcome screen.
+     *
+     * Due to Discord API limitations, a maximum of 140 characters can be used. If the character count exceeds this limit, [crate::onboarding::SerenityWelcomeScreenConvertError::TooLongDescription] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub description: Option<String>,
+    /**
+     * The channels shown in the welcome screen. (up to 5 channels)
+     *
+     * Due to Discord API limitations, only 5 channels can be shown. Any additional channels will result in a [crate::onboarding::SerenityWelcomeScreenConvertError::TooManyWelcomeChannels] being returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub welcome_channels: Option<Vec<SerenityWelcomeChannel>>,
+}
+
+/// Channel structures used in [SerenityWelcomeScreen].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityWelcomeChannel {
+    /// The ID of the channel.
+    #[builder(setter(into))]
+    pub channel_id: ChannelId,
+    /**
+     * The description shown for the channel.
+     *
+     * Due to Discord API limitations, a maximum of 42 characters can be used. If the character count exceeds this limit, [crate::onboarding::SerenityWelcomeScreenConvertError::TooLongChannelDescription] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub description: String,
+    /// The emoji shown for the channel.
+    #[builder(default, setter(strip_option, into))]
+    pub emoji: Option<SerenityGuildEmoji>,
+}
+
+/// A builder for configuring the onboarding of a Discord guild.
+/// Onboarding asks new members questions (prompts) and assigns roles and channels based on their answers.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityOnboarding {
+    /// Whether onboarding is enabled.
+    #[builder(default, setter(strip_option, into))]
+    pub enabled: Option<bool>,
+    /// The channels that new members are added to by default.
+    #[builder(default, setter(strip_option, into))]
+    pub default_channel_ids: Option<Vec<ChannelId>>,
+    /**
+     * The prompts shown during onboarding and in the Channels & Roles tab. (up to 15 prompts)
+     *
+     * Any additional prompts will result in a [crate::onboarding::SerenityOnboardingConvertError::TooManyPrompts] being returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub prompts: Vec<SerenityOnboardingPrompt>,
+}
+
+/// Prompt structures used in [SerenityOnboarding].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityOnboardingPrompt {
+    /**
+     * The title of the prompt.
+     *
+     * The title must be between 1 and 100 characters. If it is not, [crate::onboarding::SerenityOnboardingConvertError::InvalidPromptTitleLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub title: String,
+    /**
+     * The options of the prompt. (1 to 50 options)
+     *
+     * Any other number of options will result in a [crate::onboarding::SerenityOnboardingConvertError::InvalidOptionCount] being returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub options: Vec<SerenityOnboardingOption>,
+    /// Whether members can only select one option. (default: false)
+    #[builder(default = false, setter(into))]
+    pub single_select: bool,
+    /// Whether the prompt must be answered during onboarding. (default: false)
+    #[builder(default = false, setter(into))]
+    pub required: bool,
+    /// Whether the prompt is shown during onboarding. If false, it is only shown in the Channels & Roles tab. (default: true)
+    #[builder(default = true, setter(into))]
+    pub in_onboarding: bool,
+}
+
+/// Option structures used in [SerenityOnboardingPrompt].
+///
+/// At least one of `role_ids` or `channel_ids` must be set, otherwise [crate::onboarding::SerenityOnboardingConvertError::EmptyOption] will be returned during conversion.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityOnboardingOption {
+    /**
+     * The title of the option.
+     *
+     * The title must be between 1 and 50 characters. If it is not, [crate::onboarding::SerenityOnboardingConvertError::InvalidOptionTitleLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub title: String,
+    /**
+     * The description of the option.
+     *
+     * A maximum of 100 characters can be used. If the character count exceeds this limit, [crate::onboarding::SerenityOnboardingConvertError::TooLongOptionDescription] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub description: Option<String>,
+    /// The emoji of the option.
+    #[builder(default, setter(strip_option, into))]
+    pub emoji: Option<SerenityGuildEmoji>,
+    /// The roles assigned to members who select the option.
+    #[builder(default, setter(strip_option, into))]
+    pub role_ids: Option<Vec<RoleId>>,
+    /// The channels members who select the option are added to.
+    #[builder(default, setter(strip_option, into))]
+    pub channel_ids: Option<Vec<ChannelId>>,
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use serenity::all::StickerId;
+
+use crate::model::color::SerenityColor;
+use crate::model::embed::{SerenityEmbed, SerenityEmbedField};
+use crate::model::message::SerenityMessageMentionType;
+
+/// A change to a single optional value in a patch.
+///
+/// In JSON, an absent key keeps the value, `null` clears it and any other value replaces it:
+///
+/// ```json
+/// { "title": "New title", "description": null }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SerenityPatchValue<T> {
+    /// Keep the current value. (default)
+    #[default]
+    Keep,
+    /// Clear the current value.
+    Clear,
+    /// Replace the current value.
+    Set(T),
+}
+
+impl<T> SerenityPatchValue<T> {
+    /// Apply the change to the current value.
+    pub fn apply(self, current: Option<T>) -> Option<T> {
+        match self {
+            Self::Keep => current,
+            Self::Clear => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+}
+
+impl<T> From<T> for SerenityPatchValue<T> {
+    fn from(value: T) -> Self {
+        Self::Set(value)
+    }
+}
+
+impl From<&str> for SerenityPatchValue<String> {
+    fn from(value: &str) -> Self {
+        Self::Set(value.to_string())
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for SerenityPatchValue<T> {
+    /// Absent keys are handled by `#[serde(default)]`, so only `null` and values reach this point.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(|value| value.map_or(Self::Clear, Self::Set))
+    }
+}
+
+/// An operation on the fields of an embed in a [SerenityEmbedPatch].
+///
+/// In JSON, the operation is given by the `op` key:
+///
+/// ```json
+/// [
+///     { "op": "append", "name": "Status", "value": "Online", "inline": true },
+///     { "op": "replace", "name": "Players", "value": "12" },
+///     { "op": "remove", "name": "Notice" }
+/// ]
+/// ```
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum SerenityEmbedFieldOp {
+    /// Add the field after the existing fields.
+    Append(SerenityEmbedField),
+    /// Replace the first field with the same name.
+    ///
+    /// If there is no such field, [crate::patch::SerenityPatchError::FieldNotFound] will be returned.
+    Replace(SerenityEmbedField),
+    /// Remove every field with the name. Nothing happens if there is no such field.
+    Remove { name: String },
+}
+
+/// A partial [SerenityEmbed] used to update an existing embed with [SerenityEmbed::apply_patch].
+///
+/// Every value defaults to [SerenityPatchValue::Keep], so a patch only needs the values that changed.
+/// [SerenityEmbedPatch::fields] is applied first, then [SerenityEmbedPatch::field_ops] in order.
+///
+/// ```json
+/// {
+///     "title": "Server status",
+///     "footer_text": null,
+///     "field_ops": [{ "op": "replace", "name": "Players", "value": "12" }]
+/// }
+/// ```
+#[derive(
+    serde::Deserialize, typed_builder::TypedBuilder, Clone, Default, Debug, PartialEq, Eq, Hash,
+)]
+#[serde(default)]
+pub struct SerenityEmbedPatch {
+    /// The title of the embed.
+    #[builder(default, setter(into))]
+    pub title: SerenityPatchValue<String>,
+    /// The description of the embed.
+    #[builder(default, setter(into))]
+    pub description: SerenityPatchValue<String>,
+    /// The url of the embed.
+    #[builder(default, setter(into))]
+    pub url: SerenityPatchValue<String>,
+    /// The timestamp of the embed content.
+    #[builder(default, setter(into))]
+    pub timestamp: SerenityPatchValue<serenity::all::Timestamp>,
+    /// The color of the embed.
+    #[builder(default, setter(into))]
+    pub color: SerenityPatchValue<SerenityColor>,
+    /// The footer of the embed.
+    #[builder(default, setter(into))]
+    pub footer_text: SerenityPatchValue<String>,
+    /// The footer icon url of the embed.
+    #[builder(default, setter(into))]
+    pub footer_icon_url: SerenityPatchValue<String>,
+    /// The image url of the embed.
+    #[builder(default, setter(into))]
+    pub image_url: SerenityPatchValue<String>,
+    /// The thumbnail url of the embed.
+    #[builder(default, setter(into))]
+    pub thumbnail_url: SerenityPatchValue<String>,
+    /// The author name of the embed.
+    #[builder(default, setter(into))]
+    pub author_name: SerenityPatchValue<String>,
+    /// The author url of the embed.
+    #[builder(default, setter(into))]
+    pub author_url: SerenityPatchValue<String>,
+    /// The author icon url of the embed.
+    #[builder(default, setter(into))]
+    pub author_icon_url: SerenityPatchValue<String>,
+    /// The whole list of fields, replacing the current list.
+    #[builder(default, setter(into))]
+    pub fields: SerenityPatchValue<Vec<SerenityEmbedField>>,
+    /// Operations on single fields, applied in order after [SerenityEmbedPatch::fields].
+    #[builder(default, setter(into))]
+    pub field_ops: Vec<SerenityEmbedFieldOp>,
+}
+
+/// A partial [crate::model::message::SerenityMessage] used to update an existing message with [crate::model::message::SerenityMessage::apply_patch].
+///
+/// [SerenityMessagePatch::embeds] is applied first, then [SerenityMessagePatch::embed_patches].
+///
+/// ```json
+/// {
+///     "content": null,
+///     "embed_patches": { "0": { "title": "Server status" } }
+/// }
+/// ```
+#[derive(
+    serde::Deserialize, typed_builder::TypedBuilder, Clone, Default, Debug, PartialEq, Eq, Hash,
+)]
+#[serde(default)]
+pub struct SerenityMessagePatch {
+    /// The content of the message.
+    #[builder(default, setter(into))]
+    pub content: SerenityPatchValue<String>,
+    /// The whole list of embeds, replacing the current list.
+    #[builder(default, setter(into))]
+    pub embeds: SerenityPatchValue<Vec<SerenityEmbed>>,
+    /// Patches for single embeds, by their index in the list.
+    #[builder(default, setter(into))]
+    pub embed_patches: BTreeMap<usize, SerenityEmbedPatch>,
+    /// Whether the message is sent as a TTS message. Absent keeps the current value.
+    #[builder(default, setter(strip_option, into))]
+    pub tts: Option<bool>,
+    /// The mention type of the message.
+    #[builder(default, setter(into))]
+    pub mention_type: SerenityPatchValue<SerenityMessageMentionType>,
+    /// The stickers of the message.
+    #[builder(default, setter(into))]
+    pub sticker_ids: SerenityPatchValue<Vec<StickerId>>,
+}
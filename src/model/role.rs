@@ -0,0 +1,49 @@
+use serenity::all::Permissions;
+
+/// A builder for creating or editing Discord guild roles using Serenity.
+/// This struct allows you to specify the name, color, permissions and icon of the role.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityRole {
+    /**
+     * The name of the role.
+     *
+     * Due to Discord API limitations, the name must be between 1 and 100 characters. If it is not, [crate::role::SerenityRoleConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The color of the role.
+     *
+     * Only direct specification from color codes is allowed.
+     * e.g. `0xff0000` for red.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub color: Option<u32>,
+    /// Whether the role is displayed separately in the member list.
+    #[builder(default, setter(strip_option, into))]
+    pub hoist: Option<bool>,
+    /// Whether the role can be mentioned by everyone.
+    #[builder(default, setter(strip_option, into))]
+    pub mentionable: Option<bool>,
+    /// The permissions of the role.
+    #[builder(default, setter(strip_option, into))]
+    pub permissions: Option<Permissions>,
+    /**
+     * The icon of the role as raw image data. (boosted guilds only)
+     *
+     * Due to Discord API limitations, a maximum of 256 KB can be used. If the image exceeds this limit, [crate::role::SerenityRoleConvertError::TooLargeIcon] will be returned during conversion.
+     * Cannot be used together with `unicode_emoji`.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub icon: Option<Vec<u8>>,
+    /**
+     * The unicode emoji displayed as the icon of the role. (boosted guilds only)
+     *
+     * Cannot be used together with `icon`.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub unicode_emoji: Option<String>,
+    /// The position of the role in the role list.
+    #[builder(default, setter(strip_option, into))]
+    pub position: Option<u16>,
+}
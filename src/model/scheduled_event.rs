@@ -0,0 +1,126 @@
+use serenity::all::{ChannelId, ScheduledEventType, Timestamp};
+
+/// The type of entity a scheduled event takes place in.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityScheduledEventType {
+    /// The event takes place in a stage channel. `channel_id` is required.
+    Stage,
+    /// The event takes place in a voice channel. `channel_id` is required.
+    Voice,
+    /// The event takes place outside of Discord. `location` and `end_time` are required.
+    External,
+}
+
+impl From<SerenityScheduledEventType> for ScheduledEventType {
+    fn from(value: SerenityScheduledEventType) -> Self {
+        match value {
+            SerenityScheduledEventType::Stage => ScheduledEventType::StageInstance,
+            SerenityScheduledEventType::Voice => ScheduledEventType::Voice,
+            SerenityScheduledEventType::External => ScheduledEventType::External,
+        }
+    }
+}
+
+/// A day of the week, used in [SerenityRecurrence].
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// How a scheduled event repeats, starting at the start time of the event.
+///
+/// The variants only allow the combinations accepted by the Discord API.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityRecurrence {
+    /**
+     * Every day, or only on some days of the week.
+     *
+     * Due to Discord API limitations, only the following days can be used: Monday to Friday, Tuesday to Saturday, Sunday to Thursday, Friday and Saturday, Saturday and Sunday, or Sunday and Monday.
+     * Any other days will result in a [crate::scheduled_event::SerenityScheduledEventConvertError::InvalidRecurrenceWeekdays] being returned during conversion.
+     */
+    Daily {
+        #[serde(default)]
+        weekdays: Option<Vec<SerenityWeekday>>,
+    },
+    /// Every week, or every other week, on one day of the week.
+    Weekly {
+        weekday: SerenityWeekday,
+        #[serde(default)]
+        every_other_week: bool,
+    },
+    /**
+     * Every month on the n-th day of the week, e.g. the second Tuesday.
+     *
+     * `week` must be between 1 and 5, otherwise [crate::scheduled_event::SerenityScheduledEventConvertError::InvalidRecurrenceValue] will be returned during conversion.
+     */
+    Monthly { week: u8, weekday: SerenityWeekday },
+    /**
+     * Every year on the same date.
+     *
+     * `month` must be between 1 and 12 and `day` between 1 and 31, otherwise [crate::scheduled_event::SerenityScheduledEventConvertError::InvalidRecurrenceValue] will be returned during conversion.
+     */
+    Yearly { month: u8, day: u8 },
+}
+
+/// A builder for creating Discord guild scheduled events using Serenity.
+/// This struct allows you to specify the name, schedule and location of the event.
+///
+/// **Notes:** The privacy level is always "guild only", since it is the only value accepted by the Discord API.
+/// Serenity's [serenity::builder::CreateScheduledEvent] does not support recurrence rules, so events with a `recurrence_rule` are converted with [SerenityScheduledEvent::convert_body].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityScheduledEvent {
+    /**
+     * The name of the event.
+     *
+     * Due to Discord API limitations, the name must be between 1 and 100 characters. If it is not, [crate::scheduled_event::SerenityScheduledEventConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The description of the event.
+     *
+     * Due to Discord API limitations, a maximum of 1000 characters can be used. If the character count exceeds this limit, [crate::scheduled_event::SerenityScheduledEventConvertError::TooLongDescription] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub description: Option<String>,
+    /// The type of entity the event takes place in.
+    #[builder(setter(into))]
+    pub kind: SerenityScheduledEventType,
+    /// The time at which the event starts.
+    #[builder(setter(into))]
+    pub start_time: Timestamp,
+    /**
+     * The time at which the event ends.
+     *
+     * Required for [SerenityScheduledEventType::External] events. It must be after `start_time`, otherwise [crate::scheduled_event::SerenityScheduledEventConvertError::EndBeforeStart] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub end_time: Option<Timestamp>,
+    /// The ID of the stage or voice channel the event takes place in. Required for [SerenityScheduledEventType::Stage] and [SerenityScheduledEventType::Voice] events.
+    #[builder(default, setter(strip_option, into))]
+    pub channel_id: Option<ChannelId>,
+    /**
+     * The location of the event. Required for [SerenityScheduledEventType::External] events.
+     *
+     * Due to Discord API limitations, the location must be between 1 and 100 characters. If it is not, [crate::scheduled_event::SerenityScheduledEventConvertError::InvalidLocationLength] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub location: Option<String>,
+    /// The cover image of the event as raw image data.
+    #[builder(default, setter(strip_option, into))]
+    pub image: Option<Vec<u8>>,
+    /**
+     * How the event repeats.
+     *
+     * Events with a recurrence rule must be converted with [SerenityScheduledEvent::convert_body].
+     * [SerenityScheduledEvent::convert] returns [crate::scheduled_event::SerenityScheduledEventConvertError::UnsupportedRecurrenceRule] for them.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub recurrence_rule: Option<SerenityRecurrence>,
+}
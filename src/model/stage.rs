@@ -0,0 +1,41 @@
+use serenity::all::ScheduledEventId;
+
+/// A builder for opening or editing Discord stage instances using Serenity.
+/// This struct allows you to specify the topic of the stage and whether to notify the members.
+///
+/// **Notes:** The privacy level is always "guild only", since public stage instances are deprecated by Discord.
+/// Serenity's [serenity::builder::CreateStageInstance] cannot link a scheduled event, so stage instances with a `scheduled_event_id` are converted with [SerenityStageInstance::convert_body].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityStageInstance {
+    /**
+     * The topic of the stage instance.
+     *
+     * Due to Discord API limitations, the topic must be between 1 and 120 characters. If it is not, [crate::stage::SerenityStageInstanceConvertError::InvalidTopicLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub topic: String,
+    /// Whether to notify the members of the guild that the stage instance has started. (opening only)
+    #[builder(default, setter(strip_option, into))]
+    pub send_start_notification: Option<bool>,
+    /**
+     * The scheduled event the stage instance belongs to. (opening only)
+     *
+     * Stage instances with a scheduled event must be opened with [SerenityStageInstance::convert_body].
+     * [SerenityStageInstance::convert] returns [crate::stage::SerenityStageInstanceConvertError::UnsupportedScheduledEvent] for them.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub scheduled_event_id: Option<ScheduledEventId>,
+}
+
+/// A builder for setting the status of a Discord voice channel using Serenity.
+/// The status is displayed below the channel name in the channel list.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityVoiceStatus {
+    /**
+     * The status of the voice channel. An empty string clears the status.
+     *
+     * Due to Discord API limitations, a maximum of 500 characters can be used. If the character count exceeds this limit, [crate::stage::SerenityVoiceStatusConvertError::TooLongStatus] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub status: String,
+}
@@ -0,0 +1,99 @@
+/// The file formats accepted by Discord for guild stickers.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SerenityStickerFormat {
+    /// A static PNG image.
+    Png,
+    /// An animated PNG image.
+    Apng,
+    /// A GIF image.
+    Gif,
+    /// A Lottie animation in JSON format. (partnered or verified guilds only)
+    Lottie,
+}
+
+impl SerenityStickerFormat {
+    /// Detect the format of the sticker file from its contents.
+    ///
+    /// This is only a basic check of the file signature, so a file that passes it may still be rejected by the Discord API.
+    /// Returns `None` if the contents do not look like any of the supported formats.
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+        if let Some(mut chunks) = data.strip_prefix(PNG_SIGNATURE) {
+            // An APNG has an `acTL` chunk before the first `IDAT` chunk.
+            while chunks.len() >= 8 {
+                let length = u32::from_be_bytes([chunks[0], chunks[1], chunks[2], chunks[3]]);
+                match &chunks[4..8] {
+                    b"acTL" => return Some(Self::Apng),
+                    b"IDAT" => break,
+                    _ => {}
+                }
+                // length + type + data + crc
+                let Some(next) = 12usize
+                    .checked_add(length as usize)
+                    .and_then(|end| chunks.get(end..))
+                else {
+                    break;
+                };
+                chunks = next;
+            }
+            return Some(Self::Png);
+        }
+
+        if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            return Some(Self::Gif);
+        }
+
+        if data.trim_ascii_start().starts_with(b"{") {
+            return Some(Self::Lottie);
+        }
+
+        None
+    }
+
+    /// The file extension used when uploading a file of this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png | Self::Apng => "png",
+            Self::Gif => "gif",
+            Self::Lottie => "json",
+        }
+    }
+}
+
+/// A builder for creating or editing Discord guild stickers using Serenity.
+/// This struct allows you to specify the name, description, tags and file of the sticker.
+///
+/// Referencing existing stickers in a message is done with [crate::model::message::SerenityMessage::sticker_ids] instead.
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenitySticker {
+    /**
+     * The name of the sticker.
+     *
+     * Due to Discord API limitations, the name must be between 2 and 30 characters. If it is not, [crate::sticker::SerenityStickerConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The description of the sticker.
+     *
+     * Due to Discord API limitations, the description must be empty or between 2 and 100 characters. If it is not, [crate::sticker::SerenityStickerConvertError::InvalidDescriptionLength] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub description: Option<String>,
+    /**
+     * The autocomplete/suggestion tags of the sticker. Usually the name of a unicode emoji, e.g. `"smile"`.
+     *
+     * Due to Discord API limitations, a maximum of 200 characters can be used. If the character count exceeds this limit, [crate::sticker::SerenityStickerConvertError::TooLongTags] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub tags: String,
+    /**
+     * The sticker file as raw data. (PNG, APNG, GIF or Lottie JSON)
+     *
+     * Required when creating a sticker. The file cannot be replaced once the sticker has been created, so it is not used when editing.
+     * Due to Discord API limitations, a maximum of 512 KB can be used. If the file exceeds this limit, [crate::sticker::SerenityStickerConvertError::TooLargeFile] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub file: Option<Vec<u8>>,
+}
@@ -0,0 +1,76 @@
+use crate::model::color::SerenityColor;
+use crate::model::embed::SerenityEmbed;
+
+/// How the fields of a theme's base embed are combined with the fields of the themed embed.
+#[derive(serde::Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SerenityEmbedFieldMerge {
+    /// The fields of the base come first, followed by the fields of the embed.
+    Prepend,
+    /// The fields of the embed come first, followed by the fields of the base. (default)
+    #[default]
+    Append,
+    /// The fields of the embed are used if it has any (even an empty list), otherwise the fields of the base.
+    Replace,
+}
+
+/// The semantic presets of a [SerenityEmbedTheme].
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SerenityEmbedSeverity {
+    /// Neutral information. (default color: [SerenityColor::BLURPLE])
+    Info,
+    /// A completed action. (default color: [SerenityColor::GREEN])
+    Success,
+    /// Something that needs attention. (default color: [SerenityColor::YELLOW])
+    Warning,
+    /// A failed action. (default color: [SerenityColor::RED])
+    Error,
+}
+
+/// A theme shared by many embeds, such as a common footer, author block and color scheme.
+///
+/// Applying the theme fills the unset values of an embed from [SerenityEmbedTheme::base]. Values set on the embed always win.
+/// See [SerenityEmbedTheme::apply] for the exact merge order.
+///
+/// ```rs
+/// let theme = SerenityEmbedTheme::builder()
+///     .base(
+///         SerenityEmbed::builder()
+///             .footer_text("Powered by serenity-builder")
+///             .author_name("m1sk9")
+///             .build(),
+///     )
+///     .build();
+///
+/// let embed = theme.error(SerenityEmbed::builder().title("Failed to save.").build());
+/// ```
+#[derive(
+    serde::Deserialize, typed_builder::TypedBuilder, Clone, Default, Debug, PartialEq, Eq, Hash,
+)]
+pub struct SerenityEmbedTheme {
+    /// The embed whose values fill the unset values of themed embeds.
+    #[serde(default)]
+    #[builder(default)]
+    pub base: SerenityEmbed,
+    /// How the fields of [SerenityEmbedTheme::base] are combined with the fields of themed embeds. (default: [SerenityEmbedFieldMerge::Append])
+    #[serde(default)]
+    #[builder(default)]
+    pub field_merge: SerenityEmbedFieldMerge,
+    /// The color of [SerenityEmbedSeverity::Info] embeds. (default: [SerenityColor::BLURPLE])
+    #[serde(default)]
+    #[builder(default, setter(strip_option, into))]
+    pub info_color: Option<SerenityColor>,
+    /// The color of [SerenityEmbedSeverity::Success] embeds. (default: [SerenityColor::GREEN])
+    #[serde(default)]
+    #[builder(default, setter(strip_option, into))]
+    pub success_color: Option<SerenityColor>,
+    /// The color of [SerenityEmbedSeverity::Warning] embeds. (default: [SerenityColor::YELLOW])
+    #[serde(default)]
+    #[builder(default, setter(strip_option, into))]
+    pub warning_color: Option<SerenityColor>,
+    /// The color of [SerenityEmbedSeverity::Error] embeds. (default: [SerenityColor::RED])
+    #[serde(default)]
+    #[builder(default, setter(strip_option, into))]
+    pub error_color: Option<SerenityColor>,
+}
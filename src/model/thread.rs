@@ -0,0 +1,84 @@
+use serenity::all::{AutoArchiveDuration, ChannelType, MessageId};
+
+/// The type of thread to create.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityThreadType {
+    /// A thread that is visible to everyone who can view the parent channel.
+    Public,
+    /// A thread that is only visible to invited members and moderators.
+    Private,
+}
+
+impl From<&SerenityThreadType> for ChannelType {
+    fn from(value: &SerenityThreadType) -> Self {
+        match value {
+            SerenityThreadType::Public => ChannelType::PublicThread,
+            SerenityThreadType::Private => ChannelType::PrivateThread,
+        }
+    }
+}
+
+/// The duration (in minutes) after which a thread is automatically archived.
+///
+/// Only the values accepted by the Discord API can be represented, so unlike [serenity::model::channel::AutoArchiveDuration], no unknown values can be sent.
+#[derive(serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerenityThreadAutoArchiveDuration {
+    /// 60 minutes.
+    OneHour,
+    /// 1440 minutes.
+    OneDay,
+    /// 4320 minutes.
+    ThreeDays,
+    /// 10080 minutes.
+    OneWeek,
+}
+
+impl From<SerenityThreadAutoArchiveDuration> for AutoArchiveDuration {
+    fn from(value: SerenityThreadAutoArchiveDuration) -> Self {
+        match value {
+            SerenityThreadAutoArchiveDuration::OneHour => AutoArchiveDuration::OneHour,
+            SerenityThreadAutoArchiveDuration::OneDay => AutoArchiveDuration::OneDay,
+            SerenityThreadAutoArchiveDuration::ThreeDays => AutoArchiveDuration::ThreeDays,
+            SerenityThreadAutoArchiveDuration::OneWeek => AutoArchiveDuration::OneWeek,
+        }
+    }
+}
+
+/// A builder for creating Discord threads using Serenity.
+/// This struct allows you to create both threads started from a message and standalone threads.
+///
+/// When `starter_message` is set, pass it to [serenity::model::id::ChannelId::create_thread_from_message] together with the converted builder.
+/// Otherwise use [serenity::model::id::ChannelId::create_thread].
+#[derive(serde::Deserialize, typed_builder::TypedBuilder, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerenityThread {
+    /**
+     * The name of the thread.
+     *
+     * Due to Discord API limitations, the name must be between 1 and 100 characters. If it is not, [crate::thread::SerenityThreadConvertError::InvalidNameLength] will be returned during conversion.
+     */
+    #[builder(setter(into))]
+    pub name: String,
+    /**
+     * The type of the thread.
+     *
+     * Threads started from a message are always public. Specifying [SerenityThreadType::Private] together with `starter_message` will result in [crate::thread::SerenityThreadConvertError::PrivateThreadFromMessage] being returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub kind: Option<SerenityThreadType>,
+    /// The duration after which the thread is automatically archived.
+    #[builder(default, setter(strip_option, into))]
+    pub auto_archive_duration: Option<SerenityThreadAutoArchiveDuration>,
+    /// Whether non-moderators can add other non-moderators to the thread. (private threads only)
+    #[builder(default, setter(strip_option, into))]
+    pub invitable: Option<bool>,
+    /**
+     * The slowmode of the thread in seconds.
+     *
+     * Due to Discord API limitations, a maximum of 21600 seconds (6 hours) can be used. If this limit is exceeded, [crate::thread::SerenityThreadConvertError::TooLongSlowmode] will be returned during conversion.
+     */
+    #[builder(default, setter(strip_option, into))]
+    pub slowmode: Option<u16>,
+    /// The ID of the message to start the thread from.
+    #[builder(default, setter(strip_option, into))]
+    pub starter_message: Option<MessageId>,
+}
@@ -0,0 +1,570 @@
+use serenity::all::{CreateGuildWelcomeChannel, EditGuildWelcomeScreen, GuildWelcomeChannelEmoji};
+use serenity::json::{Value, json};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::onboarding::{
+    SerenityGuildEmoji, SerenityOnboarding, SerenityOnboardingOption, SerenityWelcomeScreen,
+};
+
+/// Errors that can occur when converting a [SerenityWelcomeScreen] to a [serenity::all::EditGuildWelcomeScreen].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityWelcomeScreenConvertError {
+    /**
+     * This occurs when the description exceeds 140 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The description is too long: {actual} characters (limit: {limit}).")]
+    TooLongDescription { actual: usize, limit: usize },
+    /**
+     * This occurs when more than 5 welcome channels are set, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("There are too many welcome channels: {actual} channels (limit: {limit}).")]
+    TooManyWelcomeChannels { actual: usize, limit: usize },
+    /**
+     * This occurs when the description of a welcome channel exceeds 42 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The description of welcome channel #{} is too long: {actual} characters (limit: {limit}).", .index + 1)]
+    TooLongChannelDescription {
+        index: usize,
+        actual: usize,
+        limit: usize,
+    },
+}
+
+impl SerenityWelcomeScreenConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongDescription { .. } | Self::TooLongChannelDescription { .. } => {
+                ErrorKind::Length
+            }
+            Self::TooManyWelcomeChannels { .. } => ErrorKind::Count,
+        }
+    }
+
+    /// The path of the offending value within the welcome screen, e.g. `welcome_channels[2].description`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongDescription { .. } => "description".to_string(),
+            Self::TooManyWelcomeChannels { .. } => "welcome_channels".to_string(),
+            Self::TooLongChannelDescription { index, .. } => {
+                format!("welcome_channels[{index}].description")
+            }
+        }
+    }
+}
+
+/// Errors that can occur when converting a [SerenityOnboarding] to a request body.
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityOnboardingConvertError {
+    /// This occurs when more than 15 prompts are set.
+    #[error("There are too many prompts: {actual} prompts (limit: {limit}).")]
+    TooManyPrompts { actual: usize, limit: usize },
+    /// This occurs when the title of a prompt is empty or exceeds 100 characters.
+    #[error("The title of prompt #{} must be between {min} and {max} characters, but is {actual}.", .prompt + 1)]
+    InvalidPromptTitleLength {
+        prompt: usize,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /// This occurs when a prompt has no options or more than 50 options.
+    #[error("Prompt #{} must have between {min} and {max} options, but has {actual}.", .prompt + 1)]
+    InvalidOptionCount {
+        prompt: usize,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /// This occurs when the title of an option is empty or exceeds 50 characters.
+    #[error("The title of option #{} of prompt #{} must be between {min} and {max} characters, but is {actual}.", .option + 1, .prompt + 1)]
+    InvalidOptionTitleLength {
+        prompt: usize,
+        option: usize,
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /// This occurs when the description of an option exceeds 100 characters.
+    #[error("The description of option #{} of prompt #{} is too long: {actual} characters (limit: {limit}).", .option + 1, .prompt + 1)]
+    TooLongOptionDescription {
+        prompt: usize,
+        option: usize,
+        actual: usize,
+        limit: usize,
+    },
+    /// This occurs when an option has neither roles nor channels. Such an option would do nothing.
+    #[error("Option #{} of prompt #{} must have at least one role or channel.", .option + 1, .prompt + 1)]
+    EmptyOption { prompt: usize, option: usize },
+}
+
+impl SerenityOnboardingConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidPromptTitleLength { .. }
+            | Self::InvalidOptionTitleLength { .. }
+            | Self::TooLongOptionDescription { .. } => ErrorKind::Length,
+            Self::TooManyPrompts { .. } | Self::InvalidOptionCount { .. } => ErrorKind::Count,
+            Self::EmptyOption { .. } => ErrorKind::Missing,
+        }
+    }
+
+    /// The path of the offending value within the onboarding, e.g. `prompts[0].options[3].title`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooManyPrompts { .. } => "prompts".to_string(),
+            Self::InvalidPromptTitleLength { prompt, .. } => format!("prompts[{prompt}].title"),
+            Self::InvalidOptionCount { prompt, .. } => format!("prompts[{prompt}].options"),
+            Self::InvalidOptionTitleLength { prompt, option, .. } => {
+                format!("prompts[{prompt}].options[{option}].title")
+            }
+            Self::TooLongOptionDescription { prompt, option, .. } => {
+                format!("prompts[{prompt}].options[{option}].description")
+            }
+            Self::EmptyOption { prompt, option } => format!("prompts[{prompt}].options[{option}]"),
+        }
+    }
+}
+
+impl SerenityWelcomeScreen {
+    /// Convert the welcome screen structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let welcome_screen = SerenityWelcomeScreen::builder()
+    ///   .description("Welcome to our server!")
+    ///   .build();
+    ///
+    /// let serenity_welcome_screen = welcome_screen.convert()?; // Result<EditGuildWelcomeScreen, SerenityWelcomeScreenConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityWelcomeScreen using the builder
+    /// let welcome_screen = SerenityWelcomeScreen::builder()
+    ///   .enabled(true)
+    ///   .description("Welcome to our server!")
+    ///   .welcome_channels(vec![
+    ///       SerenityWelcomeChannel::builder()
+    ///           .channel_id(rules_channel_id)
+    ///           .description("Read the rules")
+    ///           .emoji(SerenityGuildEmoji::Unicode("📜".to_string()))
+    ///           .build(),
+    ///   ])
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's EditGuildWelcomeScreen
+    /// let serenity_welcome_screen = welcome_screen.convert()?; // Result<EditGuildWelcomeScreen, SerenityWelcomeScreenConvertError>
+    ///
+    /// // 3. Use the converted welcome screen in your bot
+    /// guild_id.edit_welcome_screen(&ctx.http, serenity_welcome_screen).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityWelcomeScreenConvertError::TooLongDescription]: The description exceeds 140 characters.
+    /// - [SerenityWelcomeScreenConvertError::TooManyWelcomeChannels]: More than 5 welcome channels are set.
+    /// - [SerenityWelcomeScreenConvertError::TooLongChannelDescription]: The description of a welcome channel exceeds 42 characters.
+    pub fn convert(
+        &self,
+    ) -> Result<EditGuildWelcomeScreen<'static>, SerenityWelcomeScreenConvertError> {
+        let mut welcome_screen = EditGuildWelcomeScreen::new();
+
+        if let Some(enabled) = self.enabled {
+            welcome_screen = welcome_screen.enabled(enabled);
+        }
+
+        if let Some(description) = &self.description {
+            let length = description.encode_utf16().count();
+            if length > 140 {
+                return Err(SerenityWelcomeScreenConvertError::TooLongDescription {
+                    actual: length,
+                    limit: 140,
+                });
+            }
+            welcome_screen = welcome_screen.description(description);
+        }
+
+        if let Some(channels) = &self.welcome_channels {
+            if channels.len() > 5 {
+                return Err(SerenityWelcomeScreenConvertError::TooManyWelcomeChannels {
+                    actual: channels.len(),
+                    limit: 5,
+                });
+            }
+
+            let mut mapped = Vec::with_capacity(channels.len());
+            for (index, channel) in channels.iter().enumerate() {
+                let length = channel.description.encode_utf16().count();
+                if length > 42 {
+                    return Err(
+                        SerenityWelcomeScreenConvertError::TooLongChannelDescription {
+                            index,
+                            actual: length,
+                            limit: 42,
+                        },
+                    );
+                }
+
+                let mut welcome_channel =
+                    CreateGuildWelcomeChannel::new(channel.channel_id, channel.description.clone());
+                if let Some(emoji) = &channel.emoji {
+                    let emoji = match emoji {
+                        SerenityGuildEmoji::Custom { id, name } => {
+                            GuildWelcomeChannelEmoji::Custom {
+                                id: *id,
+                                name: name.clone(),
+                            }
+                        }
+                        SerenityGuildEmoji::Unicode(name) => {
+                            GuildWelcomeChannelEmoji::Unicode(name.clone())
+                        }
+                    };
+                    welcome_channel = welcome_channel.emoji(emoji);
+                }
+                mapped.push(welcome_channel);
+            }
+            welcome_screen = welcome_screen.set_welcome_channels(mapped);
+        }
+
+        Ok(welcome_screen)
+    }
+}
+
+impl SerenityOnboarding {
+    /// Convert the onboarding structure created in Builder into a request body.
+    ///
+    /// Serenity does not support guild onboarding yet, so the converted value is the JSON body for
+    /// [Modify Guild Onboarding](https://discord.com/developers/docs/resources/guild#modify-guild-onboarding) (`PUT /guilds/{guild.id}/onboarding`).
+    /// Prompts and options are identified by their position, so sending the body replaces all existing prompts.
+    ///
+    /// ```rs
+    /// let onboarding = SerenityOnboarding::builder()
+    ///   .enabled(true)
+    ///   .prompts(vec![
+    ///       SerenityOnboardingPrompt::builder()
+    ///           .title("What are you interested in?")
+    ///           .options(vec![
+    ///               SerenityOnboardingOption::builder()
+    ///                   .title("Gaming")
+    ///                   .role_ids(vec![gaming_role_id])
+    ///                   .build(),
+    ///           ])
+    ///           .build(),
+    ///   ])
+    ///   .build();
+    ///
+    /// let body = onboarding.convert()?; // Result<Value, SerenityOnboardingConvertError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityOnboardingConvertError::TooManyPrompts]: More than 15 prompts are set.
+    /// - [SerenityOnboardingConvertError::InvalidPromptTitleLength]: The title of a prompt is empty or exceeds 100 characters.
+    /// - [SerenityOnboardingConvertError::InvalidOptionCount]: A prompt has no options or more than 50 options.
+    /// - [SerenityOnboardingConvertError::InvalidOptionTitleLength]: The title of an option is empty or exceeds 50 characters.
+    /// - [SerenityOnboardingConvertError::TooLongOptionDescription]: The description of an option exceeds 100 characters.
+    /// - [SerenityOnboardingConvertError::EmptyOption]: An option has neither roles nor channels.
+    pub fn convert(&self) -> Result<Value, SerenityOnboardingConvertError> {
+        if self.prompts.len() > 15 {
+            return Err(SerenityOnboardingConvertError::TooManyPrompts {
+                actual: self.prompts.len(),
+                limit: 15,
+            });
+        }
+
+        // Discord requires an ID for every prompt and option; new ones can use any unique value.
+        let mut next_id = 0_u64;
+        let mut prompts = Vec::with_capacity(self.prompts.len());
+        for (prompt_index, prompt) in self.prompts.iter().enumerate() {
+            let title_length = prompt.title.encode_utf16().count();
+            if !(1..=100).contains(&title_length) {
+                return Err(SerenityOnboardingConvertError::InvalidPromptTitleLength {
+                    prompt: prompt_index,
+                    actual: title_length,
+                    min: 1,
+                    max: 100,
+                });
+            }
+            if !(1..=50).contains(&prompt.options.len()) {
+                return Err(SerenityOnboardingConvertError::InvalidOptionCount {
+                    prompt: prompt_index,
+                    actual: prompt.options.len(),
+                    min: 1,
+                    max: 50,
+                });
+            }
+
+            next_id += 1;
+            let prompt_id = next_id;
+
+            let mut options = Vec::with_capacity(prompt.options.len());
+            for (option_index, option) in prompt.options.iter().enumerate() {
+                next_id += 1;
+                options.push(convert_option(
+                    option,
+                    next_id,
+                    (prompt_index, option_index),
+                )?);
+            }
+
+            prompts.push(json!({
+                "id": prompt_id.to_string(),
+                // Multiple choice
+                "type": 0,
+                "title": prompt.title,
+                "options": options,
+                "single_select": prompt.single_select,
+                "required": prompt.required,
+                "in_onboarding": prompt.in_onboarding,
+            }));
+        }
+
+        let mut body = json!({ "prompts": prompts });
+        if let Some(enabled) = self.enabled {
+            body["enabled"] = json!(enabled);
+        }
+        if let Some(channel_ids) = &self.default_channel_ids {
+            body["default_channel_ids"] = json!(channel_ids);
+        }
+
+        Ok(body)
+    }
+}
+
+/// `position` is the index of the prompt and the index of the option within it, used for error reporting.
+fn convert_option(
+    option: &SerenityOnboardingOption,
+    id: u64,
+    position: (usize, usize),
+) -> Result<Value, SerenityOnboardingConvertError> {
+    let (prompt, index) = position;
+
+    let title_length = option.title.encode_utf16().count();
+    if !(1..=50).contains(&title_length) {
+        return Err(SerenityOnboardingConvertError::InvalidOptionTitleLength {
+            prompt,
+            option: index,
+            actual: title_length,
+            min: 1,
+            max: 50,
+        });
+    }
+
+    if let Some(description) = &option.description {
+        let length = description.encode_utf16().count();
+        if length > 100 {
+            return Err(SerenityOnboardingConvertError::TooLongOptionDescription {
+                prompt,
+                option: index,
+                actual: length,
+                limit: 100,
+            });
+        }
+    }
+
+    let role_ids = option.role_ids.clone().unwrap_or_default();
+    let channel_ids = option.channel_ids.clone().unwrap_or_default();
+    if role_ids.is_empty() && channel_ids.is_empty() {
+        return Err(SerenityOnboardingConvertError::EmptyOption {
+            prompt,
+            option: index,
+        });
+    }
+
+    let mut value = json!({
+        "id": id.to_string(),
+        "title": option.title,
+        "description": option.description,
+        "role_ids": role_ids,
+        "channel_ids": channel_ids,
+    });
+
+    // Discord expects the flattened emoji fields instead of an emoji object when modifying options.
+    match &option.emoji {
+        Some(SerenityGuildEmoji::Custom { id, name }) => {
+            value["emoji_id"] = json!(id);
+            value["emoji_name"] = json!(name);
+        }
+        Some(SerenityGuildEmoji::Unicode(name)) => {
+            value["emoji_name"] = json!(name);
+        }
+        None => {}
+    }
+
+    Ok(value)
+}
+
+impl_convert!(SerenityWelcomeScreen => EditGuildWelcomeScreen<'static>, SerenityWelcomeScreenConvertError);
+impl_convert!(SerenityOnboarding => Value, SerenityOnboardingConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{ChannelId, RoleId};
+
+    use super::*;
+    use crate::model::onboarding::{
+        SerenityOnboardingOption, SerenityOnboardingPrompt, SerenityWelcomeChannel,
+    };
+
+    static MOCK_TEXT: &str = "Welcome to our server!";
+    static MOCK_ID: u64 = 123456789012345678;
+
+    fn mock_option() -> SerenityOnboardingOption {
+        SerenityOnboardingOption::builder()
+            .title("Gaming")
+            .emoji(SerenityGuildEmoji::Unicode("🎮".to_string()))
+            .role_ids(vec![RoleId::new(MOCK_ID)])
+            .build()
+    }
+
+    #[test]
+    fn test_welcome_screen_conversion() {
+        // serenity-builder
+        let mock_welcome_screen = SerenityWelcomeScreen::builder()
+            .enabled(true)
+            .description(MOCK_TEXT)
+            .welcome_channels(vec![
+                SerenityWelcomeChannel::builder()
+                    .channel_id(ChannelId::new(MOCK_ID))
+                    .description("Read the rules")
+                    .emoji(SerenityGuildEmoji::Unicode("📜".to_string()))
+                    .build(),
+            ])
+            .build();
+        // serenity
+        let serenity_welcome_screen = EditGuildWelcomeScreen::new()
+            .enabled(true)
+            .description(MOCK_TEXT)
+            .set_welcome_channels(vec![
+                CreateGuildWelcomeChannel::new(
+                    ChannelId::new(MOCK_ID),
+                    "Read the rules".to_string(),
+                )
+                .emoji(GuildWelcomeChannelEmoji::Unicode("📜".to_string())),
+            ]);
+
+        let converted = mock_welcome_screen.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_welcome_screen).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_welcome_screen_conversion_too_many_channels() {
+        let channel = SerenityWelcomeChannel::builder()
+            .channel_id(ChannelId::new(MOCK_ID))
+            .description("Read the rules")
+            .build();
+        let mock_welcome_screen = SerenityWelcomeScreen::builder()
+            .welcome_channels(vec![channel; 6])
+            .build();
+
+        let converted = mock_welcome_screen.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityWelcomeScreenConvertError::TooManyWelcomeChannels {
+                actual: 6,
+                limit: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn test_onboarding_conversion() {
+        let mock_onboarding = SerenityOnboarding::builder()
+            .enabled(true)
+            .prompts(vec![
+                SerenityOnboardingPrompt::builder()
+                    .title("What are you interested in?")
+                    .options(vec![mock_option()])
+                    .single_select(true)
+                    .build(),
+            ])
+            .build();
+
+        let converted = mock_onboarding.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            converted.unwrap(),
+            json!({
+                "enabled": true,
+                "prompts": [{
+                    "id": "1",
+                    "type": 0,
+                    "title": "What are you interested in?",
+                    "options": [{
+                        "id": "2",
+                        "title": "Gaming",
+                        "description": null,
+                        "emoji_name": "🎮",
+                        "role_ids": [MOCK_ID.to_string()],
+                        "channel_ids": [],
+                    }],
+                    "single_select": true,
+                    "required": false,
+                    "in_onboarding": true,
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_onboarding_conversion_invalid_options() {
+        let no_options = SerenityOnboarding::builder()
+            .prompts(vec![
+                SerenityOnboardingPrompt::builder()
+                    .title(MOCK_TEXT)
+                    .options(vec![])
+                    .build(),
+            ])
+            .build();
+        let empty_option = SerenityOnboarding::builder()
+            .prompts(vec![
+                SerenityOnboardingPrompt::builder()
+                    .title(MOCK_TEXT)
+                    .options(vec![
+                        SerenityOnboardingOption::builder().title("Gaming").build(),
+                    ])
+                    .build(),
+            ])
+            .build();
+
+        assert!(matches!(
+            no_options.convert(),
+            Err(SerenityOnboardingConvertError::InvalidOptionCount {
+                prompt: 0,
+                actual: 0,
+                ..
+            })
+        ));
+
+        let error = empty_option.convert().unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityOnboardingConvertError::EmptyOption {
+                prompt: 0,
+                option: 0
+            }
+        ));
+        assert_eq!(error.field_path(), "prompts[0].options[0]");
+    }
+}
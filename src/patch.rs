@@ -0,0 +1,465 @@
+use crate::embed::SerenityEmbedConvertError;
+use crate::error::ErrorKind;
+use crate::message::SerenityMessageConvertError;
+use crate::model::embed::{SerenityEmbed, SerenityEmbedField};
+use crate::model::message::SerenityMessage;
+use crate::model::patch::{SerenityEmbedFieldOp, SerenityEmbedPatch, SerenityMessagePatch};
+
+/// Errors that can occur when merging or patching a [SerenityEmbed] or a [SerenityMessage].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityPatchError {
+    /// This occurs when [SerenityEmbedFieldOp::Replace] names a field that the embed does not have.
+    #[error("There is no field named {name:?} to replace.")]
+    FieldNotFound { name: String },
+    /// This occurs when [SerenityMessagePatch::embed_patches] has an index that the message does not have.
+    #[error("There is no embed #{} to patch (embeds: {actual}).", .index + 1)]
+    EmbedNotFound { index: usize, actual: usize },
+    /**
+     * This occurs when patching an embed of a message fails.
+     * The index of the embed within the message is included.
+     * The message of the embed error is part of this message, so it is not reported again as the source.
+     */
+    #[error("Embed #{}: {error}", .index + 1)]
+    EmbedPatchError {
+        index: usize,
+        error: Box<SerenityPatchError>,
+    },
+    /// This occurs when the merged or patched embed is no longer valid, e.g. it has more than 25 fields.
+    #[error(transparent)]
+    Embed(#[from] SerenityEmbedConvertError),
+    /// This occurs when the merged or patched message is no longer valid, e.g. its content is too long.
+    #[error(transparent)]
+    Message(#[from] SerenityMessageConvertError),
+}
+
+impl SerenityPatchError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::FieldNotFound { .. } | Self::EmbedNotFound { .. } => ErrorKind::Missing,
+            Self::EmbedPatchError { error, .. } => error.kind(),
+            Self::Embed(e) => e.kind(),
+            Self::Message(e) => e.kind(),
+        }
+    }
+
+    /// The path of the offending value, e.g. `embeds[1].fields`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::FieldNotFound { .. } => "fields".to_string(),
+            Self::EmbedNotFound { index, .. } => format!("embeds[{index}]"),
+            Self::EmbedPatchError { index, error } => {
+                format!("embeds[{index}].{}", error.field_path())
+            }
+            Self::Embed(e) => e.field_path(),
+            Self::Message(e) => e.field_path(),
+        }
+    }
+}
+
+impl SerenityEmbed {
+    /// Combine two embeds. Values set on `other` win, and the fields of `other` are added after the fields of this embed.
+    ///
+    /// ```rs
+    /// let embed = stored.merge(&update)?; // Result<SerenityEmbed, SerenityPatchError>
+    /// ```
+    ///
+    /// To fill only the unset values from a shared base embed, use a `SerenityEmbedTheme` (`theme` feature) instead.
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityPatchError::Embed]: The merged embed is not valid. (see [SerenityEmbed::convert])
+    pub fn merge(&self, other: &SerenityEmbed) -> Result<SerenityEmbed, SerenityPatchError> {
+        let other = other.clone();
+        let fields = match (self.fields.clone(), other.fields) {
+            (Some(mut fields), Some(other_fields)) => {
+                fields.extend(other_fields);
+                Some(fields)
+            }
+            (fields, other_fields) => other_fields.or(fields),
+        };
+
+        let merged = SerenityEmbed {
+            title: other.title.or_else(|| self.title.clone()),
+            description: other.description.or_else(|| self.description.clone()),
+            url: other.url.or_else(|| self.url.clone()),
+            timestamp: other.timestamp.or(self.timestamp),
+            color: other.color.or(self.color),
+            footer_text: other.footer_text.or_else(|| self.footer_text.clone()),
+            footer_icon_url: other
+                .footer_icon_url
+                .or_else(|| self.footer_icon_url.clone()),
+            image_url: other.image_url.or_else(|| self.image_url.clone()),
+            thumbnail_url: other.thumbnail_url.or_else(|| self.thumbnail_url.clone()),
+            author_name: other.author_name.or_else(|| self.author_name.clone()),
+            author_url: other.author_url.or_else(|| self.author_url.clone()),
+            author_icon_url: other
+                .author_icon_url
+                .or_else(|| self.author_icon_url.clone()),
+            fields,
+        };
+        merged
+            .convert()
+            .map(|_| merged)
+            .map_err(SerenityPatchError::from)
+    }
+
+    /// Apply a partial update, such as a patch deserialized from JSON.
+    ///
+    /// ```rs
+    /// let patch: SerenityEmbedPatch = serde_json::from_str(r#"{ "title": "Updated", "footer_text": null }"#)?;
+    /// let embed = stored.apply_patch(patch)?; // Result<SerenityEmbed, SerenityPatchError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityPatchError::FieldNotFound]: A [SerenityEmbedFieldOp::Replace] names a field that the embed does not have.
+    /// - [SerenityPatchError::Embed]: The patched embed is not valid. (see [SerenityEmbed::convert])
+    pub fn apply_patch(
+        &self,
+        patch: SerenityEmbedPatch,
+    ) -> Result<SerenityEmbed, SerenityPatchError> {
+        let patched = self.patched(patch)?;
+        patched
+            .convert()
+            .map(|_| patched)
+            .map_err(SerenityPatchError::from)
+    }
+
+    /// Apply a patch without validating the result.
+    fn patched(&self, patch: SerenityEmbedPatch) -> Result<SerenityEmbed, SerenityPatchError> {
+        let mut fields = patch.fields.apply(self.fields.clone());
+        for op in patch.field_ops {
+            apply_field_op(&mut fields, op)?;
+        }
+
+        Ok(SerenityEmbed {
+            title: patch.title.apply(self.title.clone()),
+            description: patch.description.apply(self.description.clone()),
+            url: patch.url.apply(self.url.clone()),
+            timestamp: patch.timestamp.apply(self.timestamp),
+            color: patch.color.apply(self.color),
+            footer_text: patch.footer_text.apply(self.footer_text.clone()),
+            footer_icon_url: patch.footer_icon_url.apply(self.footer_icon_url.clone()),
+            image_url: patch.image_url.apply(self.image_url.clone()),
+            thumbnail_url: patch.thumbnail_url.apply(self.thumbnail_url.clone()),
+            author_name: patch.author_name.apply(self.author_name.clone()),
+            author_url: patch.author_url.apply(self.author_url.clone()),
+            author_icon_url: patch.author_icon_url.apply(self.author_icon_url.clone()),
+            fields,
+        })
+    }
+}
+
+fn apply_field_op(
+    fields: &mut Option<Vec<SerenityEmbedField>>,
+    op: SerenityEmbedFieldOp,
+) -> Result<(), SerenityPatchError> {
+    match op {
+        SerenityEmbedFieldOp::Append(field) => fields.get_or_insert_with(Vec::new).push(field),
+        SerenityEmbedFieldOp::Replace(field) => {
+            let existing = fields
+                .iter_mut()
+                .flatten()
+                .find(|existing| existing.name == field.name)
+                .ok_or_else(|| SerenityPatchError::FieldNotFound {
+                    name: field.name.clone(),
+                })?;
+            *existing = field;
+        }
+        SerenityEmbedFieldOp::Remove { name } => {
+            if let Some(fields) = fields {
+                fields.retain(|field| field.name != name);
+            }
+        }
+    }
+    Ok(())
+}
+
+impl SerenityMessage {
+    /// Combine two messages. Values set on `other` win, and the embeds of `other` are added after the embeds of this message.
+    ///
+    /// The merged message is sent as a TTS message if either message is.
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityPatchError::Message]: The merged message is not valid. (see [SerenityMessage::convert])
+    pub fn merge(&self, other: &SerenityMessage) -> Result<SerenityMessage, SerenityPatchError> {
+        let other = other.clone();
+        let embeds = match (self.embeds.clone(), other.embeds) {
+            (Some(mut embeds), Some(other_embeds)) => {
+                embeds.extend(other_embeds);
+                Some(embeds)
+            }
+            (embeds, other_embeds) => other_embeds.or(embeds),
+        };
+
+        let merged = SerenityMessage {
+            content: other.content.or_else(|| self.content.clone()),
+            embeds,
+            tts: other.tts || self.tts,
+            mention_type: other.mention_type.or_else(|| self.mention_type.clone()),
+            sticker_ids: other.sticker_ids.or_else(|| self.sticker_ids.clone()),
+        };
+        merged
+            .convert()
+            .map(|_| merged)
+            .map_err(SerenityPatchError::from)
+    }
+
+    /// Apply a partial update, such as a patch deserialized from JSON.
+    ///
+    /// ```rs
+    /// let patch: SerenityMessagePatch = serde_json::from_str(r#"{ "embed_patches": { "0": { "title": "Updated" } } }"#)?;
+    /// let message = stored.apply_patch(patch)?; // Result<SerenityMessage, SerenityPatchError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityPatchError::EmbedNotFound]: An index of [SerenityMessagePatch::embed_patches] is out of range.
+    /// - [SerenityPatchError::EmbedPatchError]: Failed to patch an embed. (error [SerenityPatchError] with the index of the embed)
+    /// - [SerenityPatchError::Message]: The patched message is not valid. (see [SerenityMessage::convert])
+    pub fn apply_patch(
+        &self,
+        patch: SerenityMessagePatch,
+    ) -> Result<SerenityMessage, SerenityPatchError> {
+        let mut embeds = patch.embeds.apply(self.embeds.clone());
+        for (index, embed_patch) in patch.embed_patches {
+            let actual = embeds.as_ref().map_or(0, Vec::len);
+            let embed = embeds
+                .iter_mut()
+                .flatten()
+                .nth(index)
+                .ok_or(SerenityPatchError::EmbedNotFound { index, actual })?;
+            *embed = embed.patched(embed_patch).map_err(|error| {
+                SerenityPatchError::EmbedPatchError {
+                    index,
+                    error: Box::new(error),
+                }
+            })?;
+        }
+
+        let patched = SerenityMessage {
+            content: patch.content.apply(self.content.clone()),
+            embeds,
+            tts: patch.tts.unwrap_or(self.tts),
+            mention_type: patch.mention_type.apply(self.mention_type.clone()),
+            sticker_ids: patch.sticker_ids.apply(self.sticker_ids.clone()),
+        };
+        patched
+            .convert()
+            .map(|_| patched)
+            .map_err(SerenityPatchError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::CreateEmbed;
+    use serenity::json::json;
+
+    use super::*;
+    use crate::model::color::SerenityColor;
+    use crate::model::patch::SerenityPatchValue;
+
+    static MOCK_TEXT: &str = "This is a test text.";
+    static MOCK_URL: &str = "https://example.com";
+
+    fn field(name: &str, value: &str) -> SerenityEmbedField {
+        SerenityEmbedField::builder()
+            .name(name)
+            .value(value)
+            .build()
+    }
+
+    fn mock_embed() -> SerenityEmbed {
+        SerenityEmbed::builder()
+            .title(MOCK_TEXT)
+            .description(MOCK_TEXT)
+            .footer_text(MOCK_TEXT)
+            .fields(vec![field("Status", "Offline"), field("Notice", MOCK_TEXT)])
+            .build()
+    }
+
+    #[test]
+    fn test_embed_apply_patch() {
+        let patch: SerenityEmbedPatch = serde_json::from_value(json!({
+            "title": "Server status",
+            "footer_text": null,
+            "color": "#57F287",
+            "field_ops": [
+                { "op": "replace", "name": "Status", "value": "Online" },
+                { "op": "remove", "name": "Notice" },
+                { "op": "append", "name": "Players", "value": "12", "inline": true }
+            ]
+        }))
+        .unwrap();
+
+        // serenity-builder
+        let patched = mock_embed().apply_patch(patch).unwrap();
+        // serenity
+        let serenity_embed = CreateEmbed::default()
+            .title("Server status")
+            .description(MOCK_TEXT)
+            .color(serenity::all::Colour(SerenityColor::GREEN.value()))
+            .fields(vec![
+                ("Status".to_string(), "Online".to_string(), false),
+                ("Players".to_string(), "12".to_string(), true),
+            ]);
+
+        assert_eq!(
+            serde_json::to_value(patched.convert().unwrap()).unwrap(),
+            serde_json::to_value(serenity_embed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_embed_apply_patch_builder() {
+        let patch = SerenityEmbedPatch::builder()
+            .url(MOCK_URL)
+            .description(SerenityPatchValue::Clear)
+            .fields(vec![field("Only", MOCK_TEXT)])
+            .build();
+
+        let patched = mock_embed().apply_patch(patch).unwrap();
+
+        assert_eq!(patched.title.as_deref(), Some(MOCK_TEXT));
+        assert_eq!(patched.url.as_deref(), Some(MOCK_URL));
+        assert!(patched.description.is_none());
+        assert_eq!(patched.fields.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_embed_apply_patch_errors() {
+        let patch = SerenityEmbedPatch::builder()
+            .field_ops(vec![SerenityEmbedFieldOp::Replace(field(
+                "Missing", MOCK_TEXT,
+            ))])
+            .build();
+        let error = mock_embed().apply_patch(patch).err().unwrap();
+        assert!(
+            matches!(error, SerenityPatchError::FieldNotFound { ref name } if name == "Missing")
+        );
+        assert_eq!(error.kind(), ErrorKind::Missing);
+
+        let patch = SerenityEmbedPatch::builder()
+            .description("a".repeat(5000))
+            .build();
+        let error = mock_embed().apply_patch(patch).err().unwrap();
+        assert!(matches!(
+            error,
+            SerenityPatchError::Embed(SerenityEmbedConvertError::TooLongDescription { .. })
+        ));
+        assert_eq!(error.field_path(), "description");
+    }
+
+    #[test]
+    fn test_embed_merge() {
+        let other = SerenityEmbed::builder()
+            .title("Other")
+            .fields(vec![field("Players", "12")])
+            .build();
+
+        let merged = mock_embed().merge(&other).unwrap();
+
+        assert_eq!(merged.title.as_deref(), Some("Other"));
+        assert_eq!(merged.footer_text.as_deref(), Some(MOCK_TEXT));
+        assert_eq!(merged.fields.unwrap().len(), 3);
+
+        let many = SerenityEmbed::builder()
+            .fields(vec![field("Field", MOCK_TEXT); 24])
+            .build();
+        let error = mock_embed().merge(&many).err().unwrap();
+        assert!(matches!(
+            error,
+            SerenityPatchError::Embed(SerenityEmbedConvertError::TooManyFields { actual: 26, .. })
+        ));
+    }
+
+    #[test]
+    fn test_message_apply_patch() {
+        let message = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![mock_embed()])
+            .build();
+        let patch: SerenityMessagePatch = serde_json::from_value(json!({
+            "content": null,
+            "tts": true,
+            "embed_patches": { "0": { "title": "Server status" } }
+        }))
+        .unwrap();
+
+        let patched = message.apply_patch(patch).unwrap();
+
+        assert!(patched.content.is_none());
+        assert!(patched.tts);
+        assert_eq!(
+            patched.embeds.unwrap()[0].title.as_deref(),
+            Some("Server status")
+        );
+    }
+
+    #[test]
+    fn test_message_apply_patch_errors() {
+        let message = SerenityMessage::builder()
+            .embeds(vec![mock_embed()])
+            .build();
+
+        let patch: SerenityMessagePatch = serde_json::from_value(json!({
+            "embed_patches": { "1": { "title": MOCK_TEXT } }
+        }))
+        .unwrap();
+        let error = message.apply_patch(patch).err().unwrap();
+        assert!(matches!(
+            error,
+            SerenityPatchError::EmbedNotFound {
+                index: 1,
+                actual: 1
+            }
+        ));
+
+        let patch: SerenityMessagePatch = serde_json::from_value(json!({
+            "embed_patches": { "0": { "field_ops": [{ "op": "replace", "name": "Missing", "value": "" }] } }
+        }))
+        .unwrap();
+        let error = message.apply_patch(patch).err().unwrap();
+        assert_eq!(error.field_path(), "embeds[0].fields");
+        assert_eq!(
+            error.to_string(),
+            "Embed #1: There is no field named \"Missing\" to replace."
+        );
+        assert!(std::error::Error::source(&error).is_none());
+
+        let patch = SerenityMessagePatch::builder()
+            .content("a".repeat(2001))
+            .build();
+        let error = message.apply_patch(patch).err().unwrap();
+        assert_eq!(error.field_path(), "content");
+    }
+
+    #[test]
+    fn test_message_merge() {
+        let message = SerenityMessage::builder()
+            .content(MOCK_TEXT)
+            .embeds(vec![mock_embed()])
+            .build();
+        let other = SerenityMessage::builder()
+            .embeds(vec![mock_embed()])
+            .tts(true)
+            .build();
+
+        let merged = message.merge(&other).unwrap();
+
+        assert_eq!(merged.content.as_deref(), Some(MOCK_TEXT));
+        assert_eq!(merged.embeds.unwrap().len(), 2);
+        assert!(merged.tts);
+    }
+}
@@ -0,0 +1,226 @@
+use serenity::all::{Colour, CreateAttachment, EditRole};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::role::SerenityRole;
+
+/// Errors that can occur when converting a [SerenityRole] to a [serenity::all::EditRole].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityRoleConvertError {
+    /**
+     * This occurs when the role name is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the role icon exceeds 256 KB, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The icon is too large: {actual} bytes (limit: {limit}).")]
+    TooLargeIcon { actual: usize, limit: usize },
+    /**
+     * This occurs when both `icon` and `unicode_emoji` are set.
+     *
+     * Serenity silently keeps only the one set last.
+     */
+    #[error("The icon and the unicode emoji cannot be set at the same time.")]
+    ConflictingIcon,
+}
+
+impl SerenityRoleConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. } => ErrorKind::Length,
+            Self::TooLargeIcon { .. } => ErrorKind::Size,
+            Self::ConflictingIcon => ErrorKind::Conflict,
+        }
+    }
+
+    /// The path of the offending value within the role.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name",
+            Self::TooLargeIcon { .. } | Self::ConflictingIcon => "icon",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityRole {
+    /// Convert the role structure created in Builder into a model usable in Serenity.
+    ///
+    /// Serenity uses [serenity::all::EditRole] for both creating and editing roles.
+    ///
+    /// ```rs
+    /// let role = SerenityRole::builder()
+    ///   .name("Moderator")
+    ///   .color(0x3498db_u32)
+    ///   .build();
+    ///
+    /// let serenity_role = role.convert()?; // Result<EditRole, SerenityRoleConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityRole using the builder
+    /// let role = SerenityRole::builder()
+    ///   .name("Moderator")
+    ///   .color(0x3498db_u32)
+    ///   .hoist(true)
+    ///   .permissions(Permissions::MANAGE_MESSAGES | Permissions::KICK_MEMBERS)
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's EditRole
+    /// let serenity_role = role.convert()?; // Result<EditRole, SerenityRoleConvertError>
+    ///
+    /// // 3. Use the converted role in your bot
+    /// guild_id.create_role(&ctx.http, serenity_role).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityRoleConvertError::InvalidNameLength]: The name is empty or exceeds 100 characters.
+    /// - [SerenityRoleConvertError::TooLargeIcon]: The icon exceeds 256 KB.
+    /// - [SerenityRoleConvertError::ConflictingIcon]: Both the icon and the unicode emoji are set.
+    pub fn convert(&self) -> Result<EditRole<'static>, SerenityRoleConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if !(1..=100).contains(&name_length) {
+            return Err(SerenityRoleConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let mut role = EditRole::new().name(&self.name);
+
+        if let Some(color) = self.color {
+            role = role.colour(Colour(color));
+        }
+
+        if let Some(hoist) = self.hoist {
+            role = role.hoist(hoist);
+        }
+
+        if let Some(mentionable) = self.mentionable {
+            role = role.mentionable(mentionable);
+        }
+
+        if let Some(permissions) = self.permissions {
+            role = role.permissions(permissions);
+        }
+
+        match (&self.icon, &self.unicode_emoji) {
+            (Some(_), Some(_)) => return Err(SerenityRoleConvertError::ConflictingIcon),
+            (Some(icon), None) => {
+                if icon.len() > 256 * 1024 {
+                    return Err(SerenityRoleConvertError::TooLargeIcon {
+                        actual: icon.len(),
+                        limit: 256 * 1024,
+                    });
+                }
+                // The file name is not sent; Discord only receives the base64 encoded data.
+                role = role.icon(Some(&CreateAttachment::bytes(icon.clone(), "icon.png")));
+            }
+            (None, Some(emoji)) => {
+                role = role.unicode_emoji(Some(emoji.clone()));
+            }
+            (None, None) => {}
+        }
+
+        if let Some(position) = self.position {
+            role = role.position(position);
+        }
+
+        Ok(role)
+    }
+}
+
+impl_convert!(SerenityRole => EditRole<'static>, SerenityRoleConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::Permissions;
+
+    use super::*;
+
+    static MOCK_NAME: &str = "Moderator";
+    static MOCK_COLOR: u32 = 0xff0000;
+
+    #[test]
+    fn test_role_conversion() {
+        // serenity-builder
+        let mock_role = SerenityRole::builder()
+            .name(MOCK_NAME)
+            .color(MOCK_COLOR)
+            .hoist(true)
+            .mentionable(false)
+            .permissions(Permissions::MANAGE_MESSAGES)
+            .unicode_emoji("🛡️")
+            .position(3_u16)
+            .build();
+        // serenity
+        let serenity_role = EditRole::new()
+            .name(MOCK_NAME)
+            .colour(Colour(MOCK_COLOR))
+            .hoist(true)
+            .mentionable(false)
+            .permissions(Permissions::MANAGE_MESSAGES)
+            .unicode_emoji(Some("🛡️".to_string()))
+            .position(3);
+
+        let converted = mock_role.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            format!("{:?}", converted.unwrap()),
+            format!("{:?}", serenity_role)
+        );
+    }
+
+    #[test]
+    fn test_role_conversion_invalid_icon() {
+        let conflicting = SerenityRole::builder()
+            .name(MOCK_NAME)
+            .icon(vec![0; 16])
+            .unicode_emoji("🛡️")
+            .build();
+        let too_large = SerenityRole::builder()
+            .name(MOCK_NAME)
+            .icon(vec![0; 256 * 1024 + 1])
+            .build();
+
+        assert!(matches!(
+            conflicting.convert(),
+            Err(SerenityRoleConvertError::ConflictingIcon)
+        ));
+        assert!(matches!(
+            too_large.convert(),
+            Err(SerenityRoleConvertError::TooLargeIcon { .. })
+        ));
+    }
+
+    #[test]
+    fn test_role_conversion_invalid_name_length() {
+        let mock_role = SerenityRole::builder().name("a".repeat(101)).build();
+
+        let converted = mock_role.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityRoleConvertError::InvalidNameLength { actual: 101, .. })
+        ));
+    }
+}
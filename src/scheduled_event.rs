@@ -0,0 +1,576 @@
+use serenity::all::{CreateAttachment, CreateScheduledEvent};
+use serenity::json::{Value, json};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::scheduled_event::{
+    SerenityRecurrence, SerenityScheduledEvent, SerenityScheduledEventType, SerenityWeekday,
+};
+
+/// Errors that can occur when converting a [SerenityScheduledEvent] to a [serenity::all::CreateScheduledEvent].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityScheduledEventConvertError {
+    /**
+     * This occurs when the event name is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the event description exceeds 1000 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The description is too long: {actual} characters (limit: {limit}).")]
+    TooLongDescription { actual: usize, limit: usize },
+    /**
+     * This occurs when the location is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The location must be between {min} and {max} characters, but is {actual}.")]
+    InvalidLocationLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /// This occurs when a stage or voice event has no `channel_id`.
+    #[error("Stage and voice events require a channel ID.")]
+    MissingChannelId,
+    /// This occurs when an external event has no `location`.
+    #[error("External events require a location.")]
+    MissingLocation,
+    /// This occurs when an external event has no `end_time`.
+    #[error("External events require an end time.")]
+    MissingEndTime,
+    /// This occurs when an external event has a `channel_id`. External events do not take place in a channel.
+    #[error("External events cannot have a channel ID.")]
+    ChannelIdOnExternalEvent,
+    /// This occurs when a stage or voice event has a `location`. Only external events can have a location.
+    #[error("Stage and voice events cannot have a location.")]
+    LocationOnChannelEvent,
+    /// This occurs when `end_time` is not after `start_time`.
+    #[error("The end time must be after the start time.")]
+    EndBeforeStart,
+    /**
+     * This occurs when the days of a [SerenityRecurrence::Daily] rule are not one of the sets accepted by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not support recurrence rules.
+     */
+    #[error(
+        "Daily events can only repeat on Monday to Friday, Tuesday to Saturday, Sunday to Thursday, Friday and Saturday, Saturday and Sunday, or Sunday and Monday."
+    )]
+    InvalidRecurrenceWeekdays,
+    /**
+     * This occurs when the week of a [SerenityRecurrence::Monthly] rule, or the month or day of a [SerenityRecurrence::Yearly] rule, is out of range.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not support recurrence rules.
+     */
+    #[error("The recurrence {field} must be between {min} and {max}, but is {actual}.")]
+    InvalidRecurrenceValue {
+        field: &'static str,
+        actual: u8,
+        min: u8,
+        max: u8,
+    },
+    /// This occurs when [SerenityScheduledEvent::convert] is used for an event with a recurrence rule, which Serenity's [CreateScheduledEvent] cannot send.
+    #[error(
+        "Serenity cannot send recurrence rules. Use `convert_body` for events with a recurrence rule."
+    )]
+    UnsupportedRecurrenceRule,
+}
+
+impl SerenityScheduledEventConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. }
+            | Self::TooLongDescription { .. }
+            | Self::InvalidLocationLength { .. } => ErrorKind::Length,
+            Self::MissingChannelId | Self::MissingLocation | Self::MissingEndTime => {
+                ErrorKind::Missing
+            }
+            Self::ChannelIdOnExternalEvent | Self::LocationOnChannelEvent => {
+                ErrorKind::Inapplicable
+            }
+            Self::EndBeforeStart | Self::InvalidRecurrenceWeekdays => ErrorKind::Conflict,
+            Self::InvalidRecurrenceValue { .. } => ErrorKind::Range,
+            Self::UnsupportedRecurrenceRule => ErrorKind::Inapplicable,
+        }
+    }
+
+    /// The path of the offending value within the scheduled event.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name".to_string(),
+            Self::TooLongDescription { .. } => "description".to_string(),
+            Self::InvalidLocationLength { .. }
+            | Self::MissingLocation
+            | Self::LocationOnChannelEvent => "location".to_string(),
+            Self::MissingChannelId | Self::ChannelIdOnExternalEvent => "channel_id".to_string(),
+            Self::MissingEndTime | Self::EndBeforeStart => "end_time".to_string(),
+            Self::InvalidRecurrenceWeekdays => "recurrence_rule.weekdays".to_string(),
+            Self::InvalidRecurrenceValue { field, .. } => format!("recurrence_rule.{field}"),
+            Self::UnsupportedRecurrenceRule => "recurrence_rule".to_string(),
+        }
+    }
+}
+
+impl SerenityScheduledEvent {
+    /// Convert the scheduled event structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let event = SerenityScheduledEvent::builder()
+    ///   .name("Community Night")
+    ///   .kind(SerenityScheduledEventType::Voice)
+    ///   .start_time(start)
+    ///   .channel_id(voice_channel_id)
+    ///   .build();
+    ///
+    /// let serenity_event = event.convert()?; // Result<CreateScheduledEvent, SerenityScheduledEventConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityScheduledEvent using the builder
+    /// let event = SerenityScheduledEvent::builder()
+    ///   .name("Offline Meetup")
+    ///   .kind(SerenityScheduledEventType::External)
+    ///   .start_time(start)
+    ///   .end_time(end)
+    ///   .location("Tokyo")
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateScheduledEvent
+    /// let serenity_event = event.convert()?; // Result<CreateScheduledEvent, SerenityScheduledEventConvertError>
+    ///
+    /// // 3. Use the converted event in your bot
+    /// guild_id.create_scheduled_event(&ctx.http, serenity_event).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityScheduledEventConvertError::InvalidNameLength]: The name is empty or exceeds 100 characters.
+    /// - [SerenityScheduledEventConvertError::TooLongDescription]: The description exceeds 1000 characters.
+    /// - [SerenityScheduledEventConvertError::InvalidLocationLength]: The location is empty or exceeds 100 characters.
+    /// - [SerenityScheduledEventConvertError::MissingChannelId]: A stage or voice event has no channel ID.
+    /// - [SerenityScheduledEventConvertError::MissingLocation]: An external event has no location.
+    /// - [SerenityScheduledEventConvertError::MissingEndTime]: An external event has no end time.
+    /// - [SerenityScheduledEventConvertError::ChannelIdOnExternalEvent]: An external event has a channel ID.
+    /// - [SerenityScheduledEventConvertError::LocationOnChannelEvent]: A stage or voice event has a location.
+    /// - [SerenityScheduledEventConvertError::EndBeforeStart]: The end time is not after the start time.
+    /// - [SerenityScheduledEventConvertError::UnsupportedRecurrenceRule]: The event has a recurrence rule. Use [SerenityScheduledEvent::convert_body] instead.
+    pub fn convert(
+        &self,
+    ) -> Result<CreateScheduledEvent<'static>, SerenityScheduledEventConvertError> {
+        if self.recurrence_rule.is_some() {
+            return Err(SerenityScheduledEventConvertError::UnsupportedRecurrenceRule);
+        }
+        self.convert_event()
+    }
+
+    /// Convert the scheduled event into a request body usable in Serenity, including the recurrence rule.
+    ///
+    /// Serenity's [CreateScheduledEvent] cannot set a recurrence rule.
+    /// The converted value is therefore the JSON body to pass to [serenity::http::Http::create_scheduled_event].
+    ///
+    /// ```rs
+    /// let event = SerenityScheduledEvent::builder()
+    ///   .name("Weekly Game Night")
+    ///   .kind(SerenityScheduledEventType::Voice)
+    ///   .start_time(start)
+    ///   .channel_id(voice_channel_id)
+    ///   .recurrence_rule(SerenityRecurrence::Weekly { weekday: SerenityWeekday::Friday, every_other_week: false })
+    ///   .build();
+    ///
+    /// let body = event.convert_body()?; // Result<Value, SerenityScheduledEventConvertError>
+    /// ctx.http.create_scheduled_event(guild_id, &body, None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the errors of [SerenityScheduledEvent::convert] except [SerenityScheduledEventConvertError::UnsupportedRecurrenceRule], and the following errors:
+    ///
+    /// - [SerenityScheduledEventConvertError::InvalidRecurrenceWeekdays]: The days of a daily rule are not accepted by Discord.
+    /// - [SerenityScheduledEventConvertError::InvalidRecurrenceValue]: The week, month or day of the rule is out of range.
+    pub fn convert_body(&self) -> Result<Value, SerenityScheduledEventConvertError> {
+        let mut body = json!(self.convert_event()?);
+        if let Some(rule) = &self.recurrence_rule {
+            body["recurrence_rule"] = self.convert_recurrence(rule)?;
+        }
+        Ok(body)
+    }
+
+    /// Convert the recurrence rule into the JSON object of the Discord API.
+    fn convert_recurrence(
+        &self,
+        rule: &SerenityRecurrence,
+    ) -> Result<Value, SerenityScheduledEventConvertError> {
+        let in_range = |field: &'static str, actual: u8, min: u8, max: u8| {
+            if (min..=max).contains(&actual) {
+                Ok(actual)
+            } else {
+                Err(SerenityScheduledEventConvertError::InvalidRecurrenceValue {
+                    field,
+                    actual,
+                    min,
+                    max,
+                })
+            }
+        };
+
+        let mut rule_json = json!({
+            "start": self.start_time,
+            "interval": 1,
+        });
+        match rule {
+            SerenityRecurrence::Daily { weekdays } => {
+                rule_json["frequency"] = json!(3);
+                if let Some(weekdays) = weekdays {
+                    let mut days: Vec<u8> =
+                        weekdays.iter().map(|day| weekday_number(*day)).collect();
+                    days.sort_unstable();
+                    days.dedup();
+                    // Monday to Friday, Tuesday to Saturday, Sunday to Thursday, Friday and Saturday, Saturday and Sunday, Sunday and Monday.
+                    const ALLOWED: [&[u8]; 6] = [
+                        &[0, 1, 2, 3, 4],
+                        &[1, 2, 3, 4, 5],
+                        &[0, 1, 2, 3, 6],
+                        &[4, 5],
+                        &[5, 6],
+                        &[0, 6],
+                    ];
+                    if !ALLOWED.contains(&days.as_slice()) {
+                        return Err(SerenityScheduledEventConvertError::InvalidRecurrenceWeekdays);
+                    }
+                    rule_json["by_weekday"] = json!(days);
+                }
+            }
+            SerenityRecurrence::Weekly {
+                weekday,
+                every_other_week,
+            } => {
+                rule_json["frequency"] = json!(2);
+                rule_json["interval"] = json!(if *every_other_week { 2 } else { 1 });
+                rule_json["by_weekday"] = json!([weekday_number(*weekday)]);
+            }
+            SerenityRecurrence::Monthly { week, weekday } => {
+                let week = in_range("week", *week, 1, 5)?;
+                rule_json["frequency"] = json!(1);
+                rule_json["by_n_weekday"] = json!([{ "n": week, "day": weekday_number(*weekday) }]);
+            }
+            SerenityRecurrence::Yearly { month, day } => {
+                let month = in_range("month", *month, 1, 12)?;
+                let day = in_range("day", *day, 1, 31)?;
+                rule_json["frequency"] = json!(0);
+                rule_json["by_month"] = json!([month]);
+                rule_json["by_month_day"] = json!([day]);
+            }
+        }
+        Ok(rule_json)
+    }
+
+    /// Validate the event and convert it without the recurrence rule.
+    fn convert_event(
+        &self,
+    ) -> Result<CreateScheduledEvent<'static>, SerenityScheduledEventConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if !(1..=100).contains(&name_length) {
+            return Err(SerenityScheduledEventConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let mut event = CreateScheduledEvent::new(self.kind.into(), &self.name, self.start_time);
+
+        if let Some(description) = &self.description {
+            let length = description.encode_utf16().count();
+            if length > 1000 {
+                return Err(SerenityScheduledEventConvertError::TooLongDescription {
+                    actual: length,
+                    limit: 1000,
+                });
+            }
+            event = event.description(description);
+        }
+
+        match self.kind {
+            SerenityScheduledEventType::Stage | SerenityScheduledEventType::Voice => {
+                if self.location.is_some() {
+                    return Err(SerenityScheduledEventConvertError::LocationOnChannelEvent);
+                }
+                let Some(channel_id) = self.channel_id else {
+                    return Err(SerenityScheduledEventConvertError::MissingChannelId);
+                };
+                event = event.channel_id(channel_id);
+            }
+            SerenityScheduledEventType::External => {
+                if self.channel_id.is_some() {
+                    return Err(SerenityScheduledEventConvertError::ChannelIdOnExternalEvent);
+                }
+                let Some(location) = &self.location else {
+                    return Err(SerenityScheduledEventConvertError::MissingLocation);
+                };
+                let location_length = location.encode_utf16().count();
+                if !(1..=100).contains(&location_length) {
+                    return Err(SerenityScheduledEventConvertError::InvalidLocationLength {
+                        actual: location_length,
+                        min: 1,
+                        max: 100,
+                    });
+                }
+                if self.end_time.is_none() {
+                    return Err(SerenityScheduledEventConvertError::MissingEndTime);
+                }
+                event = event.location(location);
+            }
+        }
+
+        if let Some(end_time) = self.end_time {
+            if end_time <= self.start_time {
+                return Err(SerenityScheduledEventConvertError::EndBeforeStart);
+            }
+            event = event.end_time(end_time);
+        }
+
+        if let Some(image) = &self.image {
+            // The file name is not sent; Discord only receives the base64 encoded data.
+            event = event.image(&CreateAttachment::bytes(image.clone(), "cover.png"));
+        }
+
+        Ok(event)
+    }
+}
+
+/// The weekday numbers of the Discord API, starting at Monday.
+fn weekday_number(day: SerenityWeekday) -> u8 {
+    match day {
+        SerenityWeekday::Monday => 0,
+        SerenityWeekday::Tuesday => 1,
+        SerenityWeekday::Wednesday => 2,
+        SerenityWeekday::Thursday => 3,
+        SerenityWeekday::Friday => 4,
+        SerenityWeekday::Saturday => 5,
+        SerenityWeekday::Sunday => 6,
+    }
+}
+
+impl_convert!(SerenityScheduledEvent => CreateScheduledEvent<'static>, SerenityScheduledEventConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{ChannelId, ScheduledEventType, Timestamp};
+
+    use super::*;
+
+    static MOCK_NAME: &str = "Community Night";
+    static MOCK_TEXT: &str = "This is a test event.";
+    static MOCK_START_STR: &str = "2024-01-01T00:00:00Z";
+    static MOCK_END_STR: &str = "2024-01-01T02:00:00Z";
+    static MOCK_CHANNEL_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_scheduled_event_conversion() {
+        let start = Timestamp::parse(MOCK_START_STR).unwrap();
+        let end = Timestamp::parse(MOCK_END_STR).unwrap();
+
+        // serenity-builder
+        let mock_event = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .kind(SerenityScheduledEventType::Voice)
+            .start_time(start)
+            .end_time(end)
+            .channel_id(ChannelId::new(MOCK_CHANNEL_ID))
+            .build();
+        // serenity
+        let serenity_event = CreateScheduledEvent::new(ScheduledEventType::Voice, MOCK_NAME, start)
+            .description(MOCK_TEXT)
+            .channel_id(ChannelId::new(MOCK_CHANNEL_ID))
+            .end_time(end);
+
+        let converted = mock_event.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_event).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_external_event_conversion() {
+        let start = Timestamp::parse(MOCK_START_STR).unwrap();
+        let end = Timestamp::parse(MOCK_END_STR).unwrap();
+
+        // serenity-builder
+        let mock_event = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::External)
+            .start_time(start)
+            .end_time(end)
+            .location("Tokyo")
+            .build();
+        // serenity
+        let serenity_event =
+            CreateScheduledEvent::new(ScheduledEventType::External, MOCK_NAME, start)
+                .location("Tokyo")
+                .end_time(end);
+
+        let converted = mock_event.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_event).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_external_event_missing_requirements() {
+        let start = Timestamp::parse(MOCK_START_STR).unwrap();
+
+        let without_location = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::External)
+            .start_time(start)
+            .build();
+        let without_end_time = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::External)
+            .start_time(start)
+            .location("Tokyo")
+            .build();
+
+        assert!(matches!(
+            without_location.convert(),
+            Err(SerenityScheduledEventConvertError::MissingLocation)
+        ));
+        assert!(matches!(
+            without_end_time.convert(),
+            Err(SerenityScheduledEventConvertError::MissingEndTime)
+        ));
+    }
+
+    #[test]
+    fn test_channel_event_missing_channel_id() {
+        let mock_event = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::Stage)
+            .start_time(Timestamp::parse(MOCK_START_STR).unwrap())
+            .build();
+
+        let converted = mock_event.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityScheduledEventConvertError::MissingChannelId)
+        ));
+    }
+
+    #[test]
+    fn test_scheduled_event_end_before_start() {
+        let mock_event = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::Voice)
+            .start_time(Timestamp::parse(MOCK_END_STR).unwrap())
+            .end_time(Timestamp::parse(MOCK_START_STR).unwrap())
+            .channel_id(ChannelId::new(MOCK_CHANNEL_ID))
+            .build();
+
+        let converted = mock_event.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityScheduledEventConvertError::EndBeforeStart)
+        ));
+    }
+
+    #[test]
+    fn test_recurring_event_conversion() {
+        let start = Timestamp::parse(MOCK_START_STR).unwrap();
+        let mock_event = SerenityScheduledEvent::builder()
+            .name(MOCK_NAME)
+            .kind(SerenityScheduledEventType::Voice)
+            .start_time(start)
+            .channel_id(ChannelId::new(MOCK_CHANNEL_ID))
+            .recurrence_rule(SerenityRecurrence::Weekly {
+                weekday: SerenityWeekday::Friday,
+                every_other_week: true,
+            })
+            .build();
+
+        // serenity-builder
+        let body = mock_event.convert_body().unwrap();
+        // serenity
+        let mut serenity_body = serde_json::to_value(
+            CreateScheduledEvent::new(ScheduledEventType::Voice, MOCK_NAME, start)
+                .channel_id(ChannelId::new(MOCK_CHANNEL_ID)),
+        )
+        .unwrap();
+        serenity_body["recurrence_rule"] = json!({
+            "start": start,
+            "frequency": 2,
+            "interval": 2,
+            "by_weekday": [4],
+        });
+
+        assert_eq!(body, serenity_body);
+        assert!(matches!(
+            mock_event.convert(),
+            Err(SerenityScheduledEventConvertError::UnsupportedRecurrenceRule)
+        ));
+    }
+
+    #[test]
+    fn test_recurring_event_invalid_rule() {
+        let event = |rule| {
+            SerenityScheduledEvent::builder()
+                .name(MOCK_NAME)
+                .kind(SerenityScheduledEventType::Voice)
+                .start_time(Timestamp::parse(MOCK_START_STR).unwrap())
+                .channel_id(ChannelId::new(MOCK_CHANNEL_ID))
+                .recurrence_rule(rule)
+                .build()
+        };
+
+        let weekend = event(SerenityRecurrence::Daily {
+            weekdays: Some(vec![SerenityWeekday::Sunday, SerenityWeekday::Saturday]),
+        });
+        assert!(weekend.convert_body().is_ok());
+
+        let odd_days = event(SerenityRecurrence::Daily {
+            weekdays: Some(vec![SerenityWeekday::Monday, SerenityWeekday::Wednesday]),
+        });
+        assert!(matches!(
+            odd_days.convert_body(),
+            Err(SerenityScheduledEventConvertError::InvalidRecurrenceWeekdays)
+        ));
+
+        let error = event(SerenityRecurrence::Yearly { month: 13, day: 1 })
+            .convert_body()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SerenityScheduledEventConvertError::InvalidRecurrenceValue {
+                field: "month",
+                actual: 13,
+                ..
+            }
+        ));
+        assert_eq!(error.field_path(), "recurrence_rule.month");
+    }
+}
@@ -0,0 +1,333 @@
+use serenity::all::{ChannelId, CreateStageInstance, EditChannel, EditStageInstance};
+use serenity::json::{Value, json};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::stage::{SerenityStageInstance, SerenityVoiceStatus};
+
+/// Errors that can occur when converting a [SerenityStageInstance] to a [serenity::all::CreateStageInstance] or [serenity::all::EditStageInstance].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityStageInstanceConvertError {
+    /**
+     * This occurs when the topic is empty or exceeds 120 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The topic must be between {min} and {max} characters, but is {actual}.")]
+    InvalidTopicLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /// This occurs when [SerenityStageInstance::convert] is used for a stage instance with a scheduled event, which Serenity's [CreateStageInstance] cannot send.
+    #[error(
+        "Serenity cannot link a scheduled event. Use `convert_body` for stage instances with a scheduled event."
+    )]
+    UnsupportedScheduledEvent,
+}
+
+impl SerenityStageInstanceConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidTopicLength { .. } => ErrorKind::Length,
+            Self::UnsupportedScheduledEvent => ErrorKind::Inapplicable,
+        }
+    }
+
+    /// The path of the offending value within the stage instance.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidTopicLength { .. } => "topic",
+            Self::UnsupportedScheduledEvent => "scheduled_event_id",
+        }
+        .to_string()
+    }
+}
+
+/// Errors that can occur when converting a [SerenityVoiceStatus] to a [serenity::all::EditChannel].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityVoiceStatusConvertError {
+    /**
+     * This occurs when the status exceeds 500 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The status is too long: {actual} characters (limit: {limit}).")]
+    TooLongStatus { actual: usize, limit: usize },
+}
+
+impl SerenityVoiceStatusConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TooLongStatus { .. } => ErrorKind::Length,
+        }
+    }
+
+    /// The path of the offending value within the voice status.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::TooLongStatus { .. } => "status",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityStageInstance {
+    /// Convert the stage instance structure created in Builder into a model usable in Serenity for opening a stage.
+    ///
+    /// ```rs
+    /// let stage = SerenityStageInstance::builder()
+    ///   .topic("Weekly podcast")
+    ///   .build();
+    ///
+    /// let serenity_stage = stage.convert()?; // Result<CreateStageInstance, SerenityStageInstanceConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityStageInstance using the builder
+    /// let stage = SerenityStageInstance::builder()
+    ///   .topic("Weekly podcast")
+    ///   .send_start_notification(true)
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateStageInstance
+    /// let serenity_stage = stage.convert()?; // Result<CreateStageInstance, SerenityStageInstanceConvertError>
+    ///
+    /// // 3. Use the converted stage instance in your bot
+    /// stage_channel_id.create_stage_instance(&ctx.http, serenity_stage).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStageInstanceConvertError::InvalidTopicLength]: The topic is empty or exceeds 120 characters.
+    /// - [SerenityStageInstanceConvertError::UnsupportedScheduledEvent]: `scheduled_event_id` is set. Use [SerenityStageInstance::convert_body] instead.
+    pub fn convert(
+        &self,
+    ) -> Result<CreateStageInstance<'static>, SerenityStageInstanceConvertError> {
+        if self.scheduled_event_id.is_some() {
+            return Err(SerenityStageInstanceConvertError::UnsupportedScheduledEvent);
+        }
+        self.convert_stage()
+    }
+
+    /// Convert the stage instance into a request body usable in Serenity for opening a stage, including the scheduled event.
+    ///
+    /// Serenity's [CreateStageInstance] cannot link a scheduled event, and fills in the channel only when it sends the request itself.
+    /// The converted value is therefore the JSON body to pass to [serenity::http::Http::create_stage_instance], with the channel included.
+    ///
+    /// ```rs
+    /// let stage = SerenityStageInstance::builder()
+    ///   .topic("Weekly podcast")
+    ///   .scheduled_event_id(event_id)
+    ///   .build();
+    ///
+    /// let body = stage.convert_body(stage_channel_id)?; // Result<Value, SerenityStageInstanceConvertError>
+    /// ctx.http.create_stage_instance(&body, None).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStageInstanceConvertError::InvalidTopicLength]: The topic is empty or exceeds 120 characters.
+    pub fn convert_body(
+        &self,
+        channel_id: impl Into<ChannelId>,
+    ) -> Result<Value, SerenityStageInstanceConvertError> {
+        let mut body = json!(self.convert_stage()?);
+        body["channel_id"] = json!(channel_id.into());
+        if let Some(event_id) = self.scheduled_event_id {
+            body["guild_scheduled_event_id"] = json!(event_id);
+        }
+        Ok(body)
+    }
+
+    /// Validate the stage instance and convert it into a [CreateStageInstance] without the scheduled event.
+    fn convert_stage(
+        &self,
+    ) -> Result<CreateStageInstance<'static>, SerenityStageInstanceConvertError> {
+        self.validate()?;
+
+        let mut stage = CreateStageInstance::new(&self.topic);
+
+        if let Some(send_start_notification) = self.send_start_notification {
+            stage = stage.send_start_notification(send_start_notification);
+        }
+
+        Ok(stage)
+    }
+
+    /// Convert the stage instance structure created in Builder into a model usable in Serenity for editing an open stage.
+    ///
+    /// `send_start_notification` and `scheduled_event_id` are not used, since the stage has already started.
+    ///
+    /// ```rs
+    /// let stage = SerenityStageInstance::builder()
+    ///   .topic("Weekly podcast: Q&A")
+    ///   .build();
+    ///
+    /// let serenity_stage = stage.convert_edit()?; // Result<EditStageInstance, SerenityStageInstanceConvertError>
+    ///
+    /// stage_channel_id.edit_stage_instance(&ctx.http, serenity_stage).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStageInstanceConvertError::InvalidTopicLength]: The topic is empty or exceeds 120 characters.
+    pub fn convert_edit(
+        &self,
+    ) -> Result<EditStageInstance<'static>, SerenityStageInstanceConvertError> {
+        self.validate()?;
+
+        Ok(EditStageInstance::new().topic(&self.topic))
+    }
+
+    /// Validate the values shared by [SerenityStageInstance::convert] and [SerenityStageInstance::convert_edit].
+    fn validate(&self) -> Result<(), SerenityStageInstanceConvertError> {
+        let topic_length = self.topic.encode_utf16().count();
+        if !(1..=120).contains(&topic_length) {
+            return Err(SerenityStageInstanceConvertError::InvalidTopicLength {
+                actual: topic_length,
+                min: 1,
+                max: 120,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl SerenityVoiceStatus {
+    /// Convert the voice status structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let status = SerenityVoiceStatus::builder()
+    ///   .status("Recording episode 42")
+    ///   .build();
+    ///
+    /// let serenity_channel = status.convert()?; // Result<EditChannel, SerenityVoiceStatusConvertError>
+    ///
+    /// voice_channel_id.edit(&ctx.http, serenity_channel).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityVoiceStatusConvertError::TooLongStatus]: The status exceeds 500 characters.
+    pub fn convert(&self) -> Result<EditChannel<'static>, SerenityVoiceStatusConvertError> {
+        let status_length = self.status.encode_utf16().count();
+        if status_length > 500 {
+            return Err(SerenityVoiceStatusConvertError::TooLongStatus {
+                actual: status_length,
+                limit: 500,
+            });
+        }
+
+        Ok(EditChannel::new().status(&self.status))
+    }
+}
+
+impl_convert!(SerenityStageInstance => CreateStageInstance<'static>, SerenityStageInstanceConvertError);
+impl_convert!(SerenityVoiceStatus => EditChannel<'static>, SerenityVoiceStatusConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::ScheduledEventId;
+
+    use super::*;
+
+    static MOCK_TEXT: &str = "Weekly podcast";
+
+    #[test]
+    fn test_stage_instance_conversion() {
+        // serenity-builder
+        let mock_stage = SerenityStageInstance::builder()
+            .topic(MOCK_TEXT)
+            .send_start_notification(true)
+            .build();
+        // serenity
+        let serenity_stage = CreateStageInstance::new(MOCK_TEXT).send_start_notification(true);
+
+        let converted = mock_stage.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_stage).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stage_instance_scheduled_event() {
+        let mock_stage = SerenityStageInstance::builder()
+            .topic(MOCK_TEXT)
+            .scheduled_event_id(ScheduledEventId::new(2))
+            .build();
+
+        assert!(matches!(
+            mock_stage.convert(),
+            Err(SerenityStageInstanceConvertError::UnsupportedScheduledEvent)
+        ));
+
+        let body = mock_stage.convert_body(ChannelId::new(1)).unwrap();
+        let mut serenity_body = serde_json::to_value(CreateStageInstance::new(MOCK_TEXT)).unwrap();
+        serenity_body["channel_id"] = json!("1");
+        serenity_body["guild_scheduled_event_id"] = json!("2");
+
+        assert_eq!(body, serenity_body);
+    }
+
+    #[test]
+    fn test_stage_instance_edit_conversion() {
+        let mock_stage = SerenityStageInstance::builder().topic(MOCK_TEXT).build();
+        let serenity_stage = EditStageInstance::new().topic(MOCK_TEXT);
+
+        let converted = mock_stage.convert_edit();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_stage).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stage_instance_conversion_invalid_topic_length() {
+        for topic in [String::new(), "a".repeat(121)] {
+            let mock_stage = SerenityStageInstance::builder().topic(topic).build();
+
+            assert!(matches!(
+                mock_stage.convert(),
+                Err(SerenityStageInstanceConvertError::InvalidTopicLength { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_voice_status_conversion() {
+        let mock_status = SerenityVoiceStatus::builder().status(MOCK_TEXT).build();
+        let too_long = SerenityVoiceStatus::builder()
+            .status("a".repeat(501))
+            .build();
+
+        assert!(mock_status.convert().is_ok());
+        assert!(matches!(
+            too_long.convert(),
+            Err(SerenityVoiceStatusConvertError::TooLongStatus {
+                actual: 501,
+                limit: 500
+            })
+        ));
+    }
+}
@@ -0,0 +1,350 @@
+use serenity::all::{CreateAttachment, CreateSticker, EditSticker};
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::sticker::{SerenitySticker, SerenityStickerFormat};
+
+/// Errors that can occur when converting a [SerenitySticker] to a [serenity::all::CreateSticker] or [serenity::all::EditSticker].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityStickerConvertError {
+    /**
+     * This occurs when the sticker name is not between 2 and 30 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the sticker description is neither empty nor between 2 and 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error(
+        "The description must be empty or between {min} and {max} characters, but is {actual}."
+    )]
+    InvalidDescriptionLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the sticker tags exceed 200 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The tags are too long: {actual} characters (limit: {limit}).")]
+    TooLongTags { actual: usize, limit: usize },
+    /// This occurs when no file is set while creating a sticker.
+    #[error("A file is required to create a sticker.")]
+    MissingFile,
+    /**
+     * This occurs when the sticker file exceeds 512 KB, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The file is too large: {actual} bytes (limit: {limit}).")]
+    TooLargeFile { actual: usize, limit: usize },
+    /// This occurs when the file does not look like a PNG, APNG, GIF or Lottie JSON file. See [SerenityStickerFormat::detect].
+    #[error("The file is not a PNG, APNG, GIF or Lottie JSON file.")]
+    UnsupportedFileFormat,
+}
+
+impl SerenityStickerConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. }
+            | Self::InvalidDescriptionLength { .. }
+            | Self::TooLongTags { .. } => ErrorKind::Length,
+            Self::MissingFile => ErrorKind::Missing,
+            Self::TooLargeFile { .. } => ErrorKind::Size,
+            Self::UnsupportedFileFormat => ErrorKind::Format,
+        }
+    }
+
+    /// The path of the offending value within the sticker.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name",
+            Self::InvalidDescriptionLength { .. } => "description",
+            Self::TooLongTags { .. } => "tags",
+            Self::MissingFile | Self::TooLargeFile { .. } | Self::UnsupportedFileFormat => "file",
+        }
+        .to_string()
+    }
+}
+
+impl SerenitySticker {
+    /// Convert the sticker structure created in Builder into a model usable in Serenity for creating a sticker.
+    ///
+    /// ```rs
+    /// let sticker = SerenitySticker::builder()
+    ///   .name("party")
+    ///   .tags("tada")
+    ///   .file(std::fs::read("party.png")?)
+    ///   .build();
+    ///
+    /// let serenity_sticker = sticker.convert()?; // Result<CreateSticker, SerenityStickerConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenitySticker using the builder
+    /// let sticker = SerenitySticker::builder()
+    ///   .name("party")
+    ///   .description("Let's party!")
+    ///   .tags("tada")
+    ///   .file(std::fs::read("party.png")?)
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateSticker
+    /// let serenity_sticker = sticker.convert()?; // Result<CreateSticker, SerenityStickerConvertError>
+    ///
+    /// // 3. Use the converted sticker in your bot
+    /// guild_id.create_sticker(&ctx.http, serenity_sticker).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStickerConvertError::InvalidNameLength]: The name is not between 2 and 30 characters.
+    /// - [SerenityStickerConvertError::InvalidDescriptionLength]: The description is neither empty nor between 2 and 100 characters.
+    /// - [SerenityStickerConvertError::TooLongTags]: The tags exceed 200 characters.
+    /// - [SerenityStickerConvertError::MissingFile]: No file is set.
+    /// - [SerenityStickerConvertError::TooLargeFile]: The file exceeds 512 KB.
+    /// - [SerenityStickerConvertError::UnsupportedFileFormat]: The file is not a PNG, APNG, GIF or Lottie JSON file.
+    pub fn convert(&self) -> Result<CreateSticker<'static>, SerenityStickerConvertError> {
+        self.validate()?;
+
+        let Some(file) = &self.file else {
+            return Err(SerenityStickerConvertError::MissingFile);
+        };
+
+        if file.len() > 512 * 1024 {
+            return Err(SerenityStickerConvertError::TooLargeFile {
+                actual: file.len(),
+                limit: 512 * 1024,
+            });
+        }
+
+        let Some(format) = SerenityStickerFormat::detect(file) else {
+            return Err(SerenityStickerConvertError::UnsupportedFileFormat);
+        };
+
+        let attachment = CreateAttachment::bytes(
+            file.clone(),
+            format!("{}.{}", self.name, format.extension()),
+        );
+        let mut sticker = CreateSticker::new(&self.name, attachment).tags(&self.tags);
+
+        if let Some(description) = &self.description {
+            sticker = sticker.description(description);
+        }
+
+        Ok(sticker)
+    }
+
+    /// Convert the sticker structure created in Builder into a model usable in Serenity for editing an existing sticker.
+    ///
+    /// `file` is not used, since Discord does not allow replacing the file of a sticker.
+    ///
+    /// ```rs
+    /// let sticker = SerenitySticker::builder()
+    ///   .name("party")
+    ///   .tags("tada")
+    ///   .build();
+    ///
+    /// let serenity_sticker = sticker.convert_edit()?; // Result<EditSticker, SerenityStickerConvertError>
+    ///
+    /// guild_id.edit_sticker(&ctx.http, sticker_id, serenity_sticker).await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityStickerConvertError::InvalidNameLength]: The name is not between 2 and 30 characters.
+    /// - [SerenityStickerConvertError::InvalidDescriptionLength]: The description is neither empty nor between 2 and 100 characters.
+    /// - [SerenityStickerConvertError::TooLongTags]: The tags exceed 200 characters.
+    pub fn convert_edit(&self) -> Result<EditSticker<'static>, SerenityStickerConvertError> {
+        self.validate()?;
+
+        let mut sticker = EditSticker::new().name(&self.name).tags(&self.tags);
+
+        if let Some(description) = &self.description {
+            sticker = sticker.description(description);
+        }
+
+        Ok(sticker)
+    }
+
+    /// Validate the values shared by [SerenitySticker::convert] and [SerenitySticker::convert_edit].
+    fn validate(&self) -> Result<(), SerenityStickerConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if !(2..=30).contains(&name_length) {
+            return Err(SerenityStickerConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 2,
+                max: 30,
+            });
+        }
+
+        if let Some(description) = &self.description {
+            let description_length = description.encode_utf16().count();
+            if description_length != 0 && !(2..=100).contains(&description_length) {
+                return Err(SerenityStickerConvertError::InvalidDescriptionLength {
+                    actual: description_length,
+                    min: 2,
+                    max: 100,
+                });
+            }
+        }
+
+        let tags_length = self.tags.encode_utf16().count();
+        if tags_length > 200 {
+            return Err(SerenityStickerConvertError::TooLongTags {
+                actual: tags_length,
+                limit: 200,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl_convert!(SerenitySticker => CreateSticker<'static>, SerenityStickerConvertError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MOCK_NAME: &str = "party";
+    static MOCK_TEXT: &str = "This is a test sticker.";
+    static MOCK_TAGS: &str = "tada";
+    static MOCK_PNG: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x00\x01\x00\x00\x00\x01\x08\x06\x00\x00\x00\x1f\x15\xc4\x89\x00\x00\x00\x00IDAT";
+    static MOCK_APNG: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x00\x01\x00\x00\x00\x01\x08\x06\x00\x00\x00\x1f\x15\xc4\x89\x00\x00\x00\x08acTL\x00\x00\x00\x01\x00\x00\x00\x00";
+
+    #[test]
+    fn test_sticker_conversion() {
+        // serenity-builder
+        let mock_sticker = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .description(MOCK_TEXT)
+            .tags(MOCK_TAGS)
+            .file(MOCK_PNG.to_vec())
+            .build();
+        // serenity
+        let serenity_sticker =
+            CreateSticker::new(MOCK_NAME, CreateAttachment::bytes(MOCK_PNG, "party.png"))
+                .description(MOCK_TEXT)
+                .tags(MOCK_TAGS);
+
+        let converted = mock_sticker.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            format!("{:?}", converted.unwrap()),
+            format!("{:?}", serenity_sticker)
+        );
+    }
+
+    #[test]
+    fn test_sticker_edit_conversion() {
+        // serenity-builder
+        let mock_sticker = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .tags(MOCK_TAGS)
+            .build();
+        // serenity
+        let serenity_sticker = EditSticker::new().name(MOCK_NAME).tags(MOCK_TAGS);
+
+        let converted = mock_sticker.convert_edit();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_sticker).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sticker_format_detection() {
+        assert_eq!(
+            SerenityStickerFormat::detect(MOCK_PNG),
+            Some(SerenityStickerFormat::Png)
+        );
+        assert_eq!(
+            SerenityStickerFormat::detect(MOCK_APNG),
+            Some(SerenityStickerFormat::Apng)
+        );
+        assert_eq!(
+            SerenityStickerFormat::detect(b"GIF89a\x01\x00"),
+            Some(SerenityStickerFormat::Gif)
+        );
+        assert_eq!(
+            SerenityStickerFormat::detect(b"  {\"v\":\"5.5.2\"}"),
+            Some(SerenityStickerFormat::Lottie)
+        );
+        assert_eq!(SerenityStickerFormat::detect(b"\xff\xd8\xff\xe0"), None);
+    }
+
+    #[test]
+    fn test_sticker_conversion_invalid_file() {
+        let missing = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .tags(MOCK_TAGS)
+            .build();
+        let too_large = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .tags(MOCK_TAGS)
+            .file([MOCK_PNG, &vec![0; 512 * 1024]].concat())
+            .build();
+        let unsupported = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .tags(MOCK_TAGS)
+            .file(b"\xff\xd8\xff\xe0".to_vec())
+            .build();
+
+        assert!(matches!(
+            missing.convert(),
+            Err(SerenityStickerConvertError::MissingFile)
+        ));
+        assert!(matches!(
+            too_large.convert(),
+            Err(SerenityStickerConvertError::TooLargeFile { .. })
+        ));
+        assert!(matches!(
+            unsupported.convert(),
+            Err(SerenityStickerConvertError::UnsupportedFileFormat)
+        ));
+    }
+
+    #[test]
+    fn test_sticker_conversion_invalid_lengths() {
+        let short_name = SerenitySticker::builder().name("a").tags(MOCK_TAGS).build();
+        let short_description = SerenitySticker::builder()
+            .name(MOCK_NAME)
+            .description("a")
+            .tags(MOCK_TAGS)
+            .build();
+
+        assert!(matches!(
+            short_name.convert_edit(),
+            Err(SerenityStickerConvertError::InvalidNameLength { actual: 1, .. })
+        ));
+        assert!(matches!(
+            short_description.convert_edit(),
+            Err(SerenityStickerConvertError::InvalidDescriptionLength { actual: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,711 @@
+use serde::Serialize;
+use serenity::all::{CreateEmbed, CreateMessage};
+use serenity::json::Value;
+
+use crate::error::SerenityBuilderError;
+use crate::markdown::escape_markdown;
+use crate::model::embed::{SerenityEmbed, SerenityEmbedField};
+use crate::model::message::SerenityMessage;
+
+/// How values inserted into a template are escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SerenityTemplateEscape {
+    /// Values are inserted as they are.
+    #[default]
+    Plain,
+    /**
+     * Markdown characters in values are escaped, so e.g. a user name containing `*` is not rendered in bold.
+     *
+     * Values are escaped in the message content, and in the title, description and field names and values of embeds.
+     * Footers and author names are not escaped, since Discord does not render Markdown there and the escapes would be shown as they are.
+     * URLs (`url`, `image_url`, etc.) are never escaped, since escaping would break them.
+     */
+    Markdown,
+}
+
+/// Errors that can occur when rendering a template.
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityTemplateError {
+    /// This occurs when the context cannot be serialized into JSON. The error is boxed since it is large.
+    #[error("The context could not be serialized.")]
+    InvalidContext(#[source] Box<serenity::Error>),
+    /// This occurs when a variable or section is not set in the context.
+    #[error("`{field}`: The variable `{name}` is not set.")]
+    MissingVariable { field: String, name: String },
+    /// This occurs when a variable is a list or an object. Use a section (`{{#name}}...{{/name}}`) for these.
+    #[error(
+        "`{field}`: The variable `{name}` is a list or an object and cannot be inserted as text."
+    )]
+    NotText { field: String, name: String },
+    /// This occurs when `{{` is not followed by `}}`.
+    #[error("`{field}`: A tag is not closed with `}}}}`.")]
+    UnclosedTag { field: String },
+    /// This occurs when a section is opened but never closed.
+    #[error("`{field}`: The section `{name}` is not closed.")]
+    UnclosedSection { field: String, name: String },
+    /// This occurs when a closing tag does not match the innermost open section.
+    #[error("`{field}`: The closing tag `{name}` does not match an open section.")]
+    UnexpectedClosingTag { field: String, name: String },
+    /**
+     * This occurs when the rendered model fails validation.
+     * The specific error details are encapsulated in the [crate::error::SerenityBuilderError].
+     */
+    #[error(transparent)]
+    Convert(#[from] SerenityBuilderError),
+}
+
+impl SerenityTemplateError {
+    /// The path of the field containing the template, e.g. `embeds[0].fields[2].value`.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidContext(_) => String::new(),
+            Self::MissingVariable { field, .. }
+            | Self::NotText { field, .. }
+            | Self::UnclosedTag { field }
+            | Self::UnclosedSection { field, .. }
+            | Self::UnexpectedClosingTag { field, .. } => field.clone(),
+            Self::Convert(e) => e.field_path(),
+        }
+    }
+}
+
+impl SerenityEmbed {
+    /// Render the `{{placeholders}}` in every string of the embed with values from the context.
+    ///
+    /// The context can be any [Serialize] value, such as a struct or a `HashMap<&str, String>`.
+    ///
+    /// # Syntax
+    ///
+    /// - `{{name}}` inserts a value. Nested values can be accessed with `{{user.name}}`.
+    /// - `{{#name}}...{{/name}}` renders its content once for each item of a list, or once if the value is `true`, a non-empty string, a number or an object. Inside, the values of the item can be used directly, and `{{.}}` inserts the item itself.
+    /// - `{{^name}}...{{/name}}` renders its content only if the value is `false`, `null`, an empty string or an empty list.
+    ///
+    /// A field whose name starts with `{{#name}}` and whose value ends with `{{/name}}` is repeated for each item, so a list can be rendered as one field per item.
+    ///
+    /// ```rs
+    /// let embed = SerenityEmbed::builder()
+    ///   .title("Results for {{match.name}}")
+    ///   .fields(vec![
+    ///       SerenityEmbedField::builder()
+    ///           .name("{{#players}}{{name}}")
+    ///           .value("{{score}} points{{/players}}")
+    ///           .inline(true)
+    ///           .build(),
+    ///   ])
+    ///   .build();
+    ///
+    /// let rendered = embed.render(&results, SerenityTemplateEscape::Markdown)?; // Result<SerenityEmbed, SerenityTemplateError>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return any error of [SerenityTemplateError] except [SerenityTemplateError::Convert].
+    pub fn render(
+        &self,
+        context: &impl Serialize,
+        escape: SerenityTemplateEscape,
+    ) -> Result<SerenityEmbed, SerenityTemplateError> {
+        let context = context_value(context)?;
+        Renderer::new(&context, escape).embed(self, "")
+    }
+
+    /// Render the embed with [SerenityEmbed::render], then convert it with [SerenityEmbed::convert].
+    ///
+    /// Validation is performed on the rendered embed, so limits apply to the final text.
+    ///
+    /// # Errors
+    ///
+    /// This function may return any error of [SerenityTemplateError]. Conversion errors are returned as [SerenityTemplateError::Convert].
+    pub fn render_convert(
+        &self,
+        context: &impl Serialize,
+        escape: SerenityTemplateEscape,
+    ) -> Result<CreateEmbed, SerenityTemplateError> {
+        let embed = self.render(context, escape)?;
+        Ok(embed.convert().map_err(SerenityBuilderError::from)?)
+    }
+}
+
+impl SerenityMessage {
+    /// Render the `{{placeholders}}` in the content and the embeds of the message with values from the context.
+    ///
+    /// For the syntax, see [SerenityEmbed::render].
+    ///
+    /// ```rs
+    /// let message = SerenityMessage::builder()
+    ///   .content("Welcome, {{user}}!")
+    ///   .build();
+    ///
+    /// let rendered = message.render(&HashMap::from([("user", member.display_name())]), SerenityTemplateEscape::Markdown)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return any error of [SerenityTemplateError] except [SerenityTemplateError::Convert].
+    pub fn render(
+        &self,
+        context: &impl Serialize,
+        escape: SerenityTemplateEscape,
+    ) -> Result<SerenityMessage, SerenityTemplateError> {
+        let context = context_value(context)?;
+        let mut renderer = Renderer::new(&context, escape);
+
+        let content = renderer.optional(self.content.as_deref(), "content", true)?;
+        let embeds = match &self.embeds {
+            Some(embeds) => Some(
+                embeds
+                    .iter()
+                    .enumerate()
+                    .map(|(index, embed)| renderer.embed(embed, &format!("embeds[{index}].")))
+                    .collect::<Result<_, _>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(SerenityMessage {
+            content,
+            embeds,
+            ..self.clone()
+        })
+    }
+
+    /// Render the message with [SerenityMessage::render], then convert it with [SerenityMessage::convert].
+    ///
+    /// Validation is performed on the rendered message, so limits apply to the final text.
+    ///
+    /// # Errors
+    ///
+    /// This function may return any error of [SerenityTemplateError]. Conversion errors are returned as [SerenityTemplateError::Convert].
+    pub fn render_convert(
+        &self,
+        context: &impl Serialize,
+        escape: SerenityTemplateEscape,
+    ) -> Result<CreateMessage, SerenityTemplateError> {
+        let message = self.render(context, escape)?;
+        Ok(message.convert().map_err(SerenityBuilderError::from)?)
+    }
+}
+
+fn context_value(context: &impl Serialize) -> Result<Value, SerenityTemplateError> {
+    serenity::json::to_value(context)
+        .map_err(|e| SerenityTemplateError::InvalidContext(Box::new(e)))
+}
+
+/// A parsed piece of a template.
+enum Node<'t> {
+    Text(&'t str),
+    Variable(&'t str),
+    Section {
+        name: &'t str,
+        inverted: bool,
+        children: Vec<Node<'t>>,
+    },
+}
+
+fn parse<'t>(template: &'t str, field: &str) -> Result<Vec<Node<'t>>, SerenityTemplateError> {
+    // The open sections; the first entry holds the top level.
+    let mut stack: Vec<(&str, bool, Vec<Node>)> = vec![("", false, Vec::new())];
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let top = &mut stack.last_mut().expect("the top level is never removed").2;
+        if start > 0 {
+            top.push(Node::Text(&rest[..start]));
+        }
+
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            return Err(SerenityTemplateError::UnclosedTag {
+                field: field.to_string(),
+            });
+        };
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+
+        if let Some(name) = tag.strip_prefix('#') {
+            stack.push((name.trim(), false, Vec::new()));
+        } else if let Some(name) = tag.strip_prefix('^') {
+            stack.push((name.trim(), true, Vec::new()));
+        } else if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            if stack.len() == 1 || stack[stack.len() - 1].0 != name {
+                return Err(SerenityTemplateError::UnexpectedClosingTag {
+                    field: field.to_string(),
+                    name: name.to_string(),
+                });
+            }
+            let (name, inverted, children) = stack.pop().expect("checked above");
+            stack
+                .last_mut()
+                .expect("checked above")
+                .2
+                .push(Node::Section {
+                    name,
+                    inverted,
+                    children,
+                });
+        } else {
+            top.push(Node::Variable(tag));
+        }
+    }
+
+    if stack.len() > 1 {
+        return Err(SerenityTemplateError::UnclosedSection {
+            field: field.to_string(),
+            name: stack[stack.len() - 1].0.to_string(),
+        });
+    }
+
+    let (_, _, mut nodes) = stack.pop().expect("the top level is never removed");
+    if !rest.is_empty() {
+        nodes.push(Node::Text(rest));
+    }
+    Ok(nodes)
+}
+
+/// If the field is wrapped in a section (`{{#name}}` at the start of the name, `{{/name}}` at the end of the value), return the section and the inner templates.
+fn field_section(field: &SerenityEmbedField) -> Option<(&str, bool, &str, &str)> {
+    let after = field.name.strip_prefix("{{")?;
+    let end = after.find("}}")?;
+    let tag = after[..end].trim();
+    let (name, inverted) = match (tag.strip_prefix('#'), tag.strip_prefix('^')) {
+        (Some(name), _) => (name.trim(), false),
+        (_, Some(name)) => (name.trim(), true),
+        _ => return None,
+    };
+
+    let value = field.value.strip_suffix("}}")?;
+    let start = value.rfind("{{")?;
+    if value[start + 2..].trim().strip_prefix('/')?.trim() != name {
+        return None;
+    }
+
+    Some((name, inverted, &after[end + 2..], &value[..start]))
+}
+
+struct Renderer<'c> {
+    /// The values in which names are looked up, innermost last.
+    scopes: Vec<&'c Value>,
+    escape: SerenityTemplateEscape,
+}
+
+impl<'c> Renderer<'c> {
+    fn new(context: &'c Value, escape: SerenityTemplateEscape) -> Self {
+        Self {
+            scopes: vec![context],
+            escape,
+        }
+    }
+
+    fn embed(
+        &mut self,
+        embed: &SerenityEmbed,
+        prefix: &str,
+    ) -> Result<SerenityEmbed, SerenityTemplateError> {
+        let fields = match &embed.fields {
+            Some(fields) => {
+                let mut rendered = Vec::with_capacity(fields.len());
+                for (index, field) in fields.iter().enumerate() {
+                    let path = format!("{prefix}fields[{index}]");
+                    match field_section(field) {
+                        Some((name, inverted, name_template, value_template)) => {
+                            for scope in self.section_scopes(name, inverted, &path)? {
+                                rendered.push(self.with_scope(scope, |renderer| {
+                                    renderer.field(
+                                        name_template,
+                                        value_template,
+                                        field.inline,
+                                        &path,
+                                    )
+                                })?);
+                            }
+                        }
+                        None => rendered.push(self.field(
+                            &field.name,
+                            &field.value,
+                            field.inline,
+                            &path,
+                        )?),
+                    }
+                }
+                Some(rendered)
+            }
+            None => None,
+        };
+
+        Ok(SerenityEmbed {
+            title: self.optional(embed.title.as_deref(), &format!("{prefix}title"), true)?,
+            description: self.optional(
+                embed.description.as_deref(),
+                &format!("{prefix}description"),
+                true,
+            )?,
+            url: self.optional(embed.url.as_deref(), &format!("{prefix}url"), false)?,
+            footer_text: self.optional(
+                embed.footer_text.as_deref(),
+                &format!("{prefix}footer_text"),
+                false,
+            )?,
+            footer_icon_url: self.optional(
+                embed.footer_icon_url.as_deref(),
+                &format!("{prefix}footer_icon_url"),
+                false,
+            )?,
+            image_url: self.optional(
+                embed.image_url.as_deref(),
+                &format!("{prefix}image_url"),
+                false,
+            )?,
+            thumbnail_url: self.optional(
+                embed.thumbnail_url.as_deref(),
+                &format!("{prefix}thumbnail_url"),
+                false,
+            )?,
+            author_name: self.optional(
+                embed.author_name.as_deref(),
+                &format!("{prefix}author_name"),
+                false,
+            )?,
+            author_url: self.optional(
+                embed.author_url.as_deref(),
+                &format!("{prefix}author_url"),
+                false,
+            )?,
+            author_icon_url: self.optional(
+                embed.author_icon_url.as_deref(),
+                &format!("{prefix}author_icon_url"),
+                false,
+            )?,
+            fields,
+            ..embed.clone()
+        })
+    }
+
+    fn field(
+        &mut self,
+        name: &str,
+        value: &str,
+        inline: bool,
+        path: &str,
+    ) -> Result<SerenityEmbedField, SerenityTemplateError> {
+        Ok(SerenityEmbedField {
+            name: self.text(name, &format!("{path}.name"), true)?,
+            value: self.text(value, &format!("{path}.value"), true)?,
+            inline,
+        })
+    }
+
+    fn optional(
+        &mut self,
+        template: Option<&str>,
+        field: &str,
+        escape: bool,
+    ) -> Result<Option<String>, SerenityTemplateError> {
+        template
+            .map(|template| self.text(template, field, escape))
+            .transpose()
+    }
+
+    /// Render a single template. `escape` is `false` for URLs, footers and author names, which are never escaped.
+    fn text(
+        &mut self,
+        template: &str,
+        field: &str,
+        escape: bool,
+    ) -> Result<String, SerenityTemplateError> {
+        let nodes = parse(template, field)?;
+        let mut out = String::with_capacity(template.len());
+        self.render(&nodes, field, escape, &mut out)?;
+        Ok(out)
+    }
+
+    fn render(
+        &mut self,
+        nodes: &[Node],
+        field: &str,
+        escape: bool,
+        out: &mut String,
+    ) -> Result<(), SerenityTemplateError> {
+        for node in nodes {
+            match node {
+                Node::Text(text) => out.push_str(text),
+                Node::Variable(name) => {
+                    let text = match self.lookup(name, field)? {
+                        Value::Null => String::new(),
+                        Value::String(s) => s.clone(),
+                        Value::Bool(b) => b.to_string(),
+                        Value::Number(n) => n.to_string(),
+                        Value::Array(_) | Value::Object(_) => {
+                            return Err(SerenityTemplateError::NotText {
+                                field: field.to_string(),
+                                name: name.to_string(),
+                            });
+                        }
+                    };
+                    match (self.escape, escape) {
+                        (SerenityTemplateEscape::Markdown, true) => {
+                            out.push_str(&escape_markdown(&text))
+                        }
+                        _ => out.push_str(&text),
+                    }
+                }
+                Node::Section {
+                    name,
+                    inverted,
+                    children,
+                } => {
+                    for scope in self.section_scopes(name, *inverted, field)? {
+                        self.with_scope(scope, |renderer| {
+                            renderer.render(children, field, escape, out)
+                        })?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Look up a (dotted) name, searching from the innermost scope outwards. `.` is the innermost scope itself.
+    fn lookup(&self, name: &str, field: &str) -> Result<&'c Value, SerenityTemplateError> {
+        let missing = || SerenityTemplateError::MissingVariable {
+            field: field.to_string(),
+            name: name.to_string(),
+        };
+
+        if name == "." {
+            return Ok(self.scopes[self.scopes.len() - 1]);
+        }
+
+        let mut parts = name.split('.');
+        let first = parts.next().unwrap_or_default();
+        let mut value = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(first))
+            .ok_or_else(missing)?;
+        for part in parts {
+            value = value.get(part).ok_or_else(missing)?;
+        }
+        Ok(value)
+    }
+
+    /// The scopes a section is rendered with: one per item of a list, a single one for other truthy values, and none for falsy values.
+    /// `None` renders the section without adding a scope.
+    fn section_scopes(
+        &self,
+        name: &str,
+        inverted: bool,
+        field: &str,
+    ) -> Result<Vec<Option<&'c Value>>, SerenityTemplateError> {
+        let value = self.lookup(name, field)?;
+        let truthy = match value {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::String(s) => !s.is_empty(),
+            Value::Array(items) => !items.is_empty(),
+            Value::Number(_) | Value::Object(_) => true,
+        };
+
+        Ok(match (inverted, truthy, value) {
+            (true, true, _) | (false, false, _) => vec![],
+            (true, false, _) => vec![None],
+            (false, true, Value::Array(items)) => items.iter().map(Some).collect(),
+            (false, true, value) => vec![Some(value)],
+        })
+    }
+
+    fn with_scope<T>(
+        &mut self,
+        scope: Option<&'c Value>,
+        f: impl FnOnce(&mut Self) -> Result<T, SerenityTemplateError>,
+    ) -> Result<T, SerenityTemplateError> {
+        if let Some(scope) = scope {
+            self.scopes.push(scope);
+        }
+        let result = f(self);
+        if scope.is_some() {
+            self.scopes.pop();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serenity::json::json;
+
+    use super::*;
+
+    static MOCK_URL: &str = "https://example.com/{{path}}";
+
+    fn mock_context() -> Value {
+        json!({
+            "user": { "name": "m1sk9_*" },
+            "path": "a_b",
+            "count": 3,
+            "players": [
+                { "name": "Alice", "score": 10 },
+                { "name": "Bob", "score": 7 },
+            ],
+            "tags": ["rust", "discord"],
+            "winner": true,
+            "empty": [],
+        })
+    }
+
+    #[test]
+    fn test_embed_render() {
+        let mock_embed = SerenityEmbed::builder()
+            .title("Hello, {{user.name}}!")
+            .description(
+                "{{count}} players: {{#tags}}[{{.}}]{{/tags}}{{^empty}} (none missing){{/empty}}",
+            )
+            .url(MOCK_URL)
+            .footer_text("Requested by {{user.name}}")
+            .author_name("{{user.name}}")
+            .fields(vec![
+                SerenityEmbedField::builder()
+                    .name("{{#players}}{{name}}")
+                    .value("{{score}} points{{/players}}")
+                    .inline(true)
+                    .build(),
+                SerenityEmbedField::builder()
+                    .name("Winner")
+                    .value("{{#winner}}Yes{{/winner}}{{^winner}}No{{/winner}}")
+                    .build(),
+            ])
+            .build();
+
+        let rendered = mock_embed
+            .render(&mock_context(), SerenityTemplateEscape::Markdown)
+            .unwrap();
+
+        assert_eq!(rendered.title.as_deref(), Some("Hello, m1sk9\\_\\*!"));
+        assert_eq!(
+            rendered.description.as_deref(),
+            Some("3 players: [rust][discord] (none missing)")
+        );
+        // URLs are never escaped.
+        assert_eq!(rendered.url.as_deref(), Some("https://example.com/a_b"));
+        // Discord does not render Markdown in footers and author names.
+        assert_eq!(
+            rendered.footer_text.as_deref(),
+            Some("Requested by m1sk9_*")
+        );
+        assert_eq!(rendered.author_name.as_deref(), Some("m1sk9_*"));
+
+        let fields = rendered.fields.unwrap();
+        let fields = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.value.as_str(), field.inline))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("Alice", "10 points", true),
+                ("Bob", "7 points", true),
+                ("Winner", "Yes", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_message_render_with_map() {
+        let mock_message = SerenityMessage::builder()
+            .content("Welcome, {{user}}!")
+            .embeds(vec![SerenityEmbed::builder().title("{{user}}").build()])
+            .build();
+        let context = HashMap::from([("user", "m1sk9_*")]);
+
+        let rendered = mock_message
+            .render(&context, SerenityTemplateEscape::Plain)
+            .unwrap();
+
+        assert_eq!(rendered.content.as_deref(), Some("Welcome, m1sk9_*!"));
+        assert_eq!(
+            rendered.embeds.unwrap()[0].title.as_deref(),
+            Some("m1sk9_*")
+        );
+    }
+
+    #[test]
+    fn test_render_missing_variable() {
+        let mock_message = SerenityMessage::builder()
+            .embeds(vec![
+                SerenityEmbed::builder()
+                    .fields(vec![
+                        SerenityEmbedField::builder()
+                            .name("Name")
+                            .value("{{unknown}}")
+                            .build(),
+                    ])
+                    .build(),
+            ])
+            .build();
+
+        let error = mock_message
+            .render(&mock_context(), SerenityTemplateEscape::Plain)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            &error,
+            SerenityTemplateError::MissingVariable { name, .. } if name == "unknown"
+        ));
+        assert_eq!(error.field_path(), "embeds[0].fields[0].value");
+    }
+
+    #[test]
+    fn test_render_invalid_context() {
+        let context = HashMap::from([((1, 2), "value")]);
+
+        let error = SerenityEmbed::builder()
+            .title("{{name}}")
+            .build()
+            .render(&context, SerenityTemplateEscape::Plain)
+            .err()
+            .unwrap();
+
+        assert!(matches!(error, SerenityTemplateError::InvalidContext(_)));
+        assert_eq!(error.to_string(), "The context could not be serialized.");
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_render_invalid_template() {
+        let unclosed_section = SerenityEmbed::builder()
+            .title("{{#players}}{{name}}")
+            .build();
+        let unclosed_tag = SerenityEmbed::builder().title("{{user.name").build();
+        let list = SerenityEmbed::builder().title("{{tags}}").build();
+
+        assert!(matches!(
+            unclosed_section.render(&mock_context(), SerenityTemplateEscape::Plain),
+            Err(SerenityTemplateError::UnclosedSection { .. })
+        ));
+        assert!(matches!(
+            unclosed_tag.render(&mock_context(), SerenityTemplateEscape::Plain),
+            Err(SerenityTemplateError::UnclosedTag { .. })
+        ));
+        assert!(matches!(
+            list.render(&mock_context(), SerenityTemplateEscape::Plain),
+            Err(SerenityTemplateError::NotText { .. })
+        ));
+    }
+
+    #[test]
+    fn test_render_convert_validates_rendered_text() {
+        let mock_message = SerenityMessage::builder()
+            .content("{{text}}{{text}}")
+            .build();
+        let context = HashMap::from([("text", "a".repeat(1500))]);
+
+        let error = mock_message
+            .render_convert(&context, SerenityTemplateEscape::Plain)
+            .unwrap_err();
+        assert!(matches!(error, SerenityTemplateError::Convert(_)));
+        assert_eq!(error.field_path(), "content");
+    }
+}
@@ -0,0 +1,168 @@
+use serenity::all::{CreateEmbed, CreateMessage};
+use serenity::json::Value;
+
+use crate::message::SerenityMessageConvertError;
+use crate::model::message::SerenityMessage;
+
+/// An in-process stand-in for Discord that records the payloads a bot would send.
+///
+/// Payloads are serialized the same way Serenity serializes them for the request body, so assertions see the exact JSON Discord would receive.
+///
+/// ```rs
+/// let mut sink = SerenityMockSink::new();
+///
+/// sink.send(&SerenityMessage::builder().content("Hello!").build())?;
+/// sink.send_embed(SerenityEmbed::builder().title("Results").build().convert()?);
+///
+/// sink.assert_sent_count(2);
+/// sink.assert_sent_content("Hello!");
+/// sink.assert_sent_embed_title("Results");
+/// assert_eq!(sink.snapshot(), include_str!("snapshots/results.json"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SerenityMockSink {
+    payloads: Vec<Value>,
+}
+
+impl SerenityMockSink {
+    /// Create an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert the message and record it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the error of [SerenityMessage::convert] and records nothing if the conversion fails.
+    pub fn send(&mut self, message: &SerenityMessage) -> Result<(), SerenityMessageConvertError> {
+        self.send_message(message.convert()?);
+        Ok(())
+    }
+
+    /// Record a message that was already converted.
+    pub fn send_message(&mut self, message: CreateMessage) {
+        // Serenity's builders only contain serializable data, so this cannot fail.
+        let payload = serenity::json::to_value(message).expect("CreateMessage is serializable");
+        self.payloads.push(payload);
+    }
+
+    /// Record an embed as a message containing only that embed.
+    pub fn send_embed(&mut self, embed: CreateEmbed) {
+        self.send_message(CreateMessage::new().embed(embed));
+    }
+
+    /// The recorded payloads, in the order they were sent.
+    pub fn payloads(&self) -> &[Value] {
+        &self.payloads
+    }
+
+    /// The most recently recorded payload.
+    pub fn last_payload(&self) -> Option<&Value> {
+        self.payloads.last()
+    }
+
+    /// Remove all recorded payloads.
+    pub fn clear(&mut self) {
+        self.payloads.clear();
+    }
+
+    /// The recorded payloads as pretty-printed JSON, for comparing against a stored snapshot.
+    pub fn snapshot(&self) -> String {
+        serenity::json::to_string_pretty(&self.payloads).expect("JSON values are serializable")
+    }
+
+    /// Assert that exactly `count` payloads were recorded.
+    #[track_caller]
+    pub fn assert_sent_count(&self, count: usize) {
+        assert_eq!(
+            self.payloads.len(),
+            count,
+            "expected {count} sent payloads, but {} were sent",
+            self.payloads.len()
+        );
+    }
+
+    /// Assert that a payload with exactly this content was recorded.
+    #[track_caller]
+    pub fn assert_sent_content(&self, content: &str) {
+        let sent = self
+            .payloads
+            .iter()
+            .filter_map(|payload| payload["content"].as_str())
+            .collect::<Vec<_>>();
+        assert!(
+            sent.contains(&content),
+            "expected a payload with content {content:?}, but the sent contents were {sent:?}"
+        );
+    }
+
+    /// Assert that an embed with exactly this title was recorded in any payload.
+    #[track_caller]
+    pub fn assert_sent_embed_title(&self, title: &str) {
+        let sent = self
+            .payloads
+            .iter()
+            .filter_map(|payload| payload["embeds"].as_array())
+            .flatten()
+            .filter_map(|embed| embed["title"].as_str())
+            .collect::<Vec<_>>();
+        assert!(
+            sent.contains(&title),
+            "expected an embed with title {title:?}, but the sent embed titles were {sent:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::json::json;
+
+    use super::*;
+    use crate::model::embed::SerenityEmbed;
+
+    static MOCK_TEXT: &str = "This is a test message.";
+
+    #[test]
+    fn test_mock_sink_records_payloads() {
+        let mut sink = SerenityMockSink::new();
+
+        sink.send(&SerenityMessage::builder().content(MOCK_TEXT).build())
+            .unwrap();
+        sink.send_embed(
+            SerenityEmbed::builder()
+                .title(MOCK_TEXT)
+                .build()
+                .convert()
+                .unwrap(),
+        );
+
+        sink.assert_sent_count(2);
+        sink.assert_sent_content(MOCK_TEXT);
+        sink.assert_sent_embed_title(MOCK_TEXT);
+        assert_eq!(sink.last_payload().unwrap()["embeds"][0]["type"], "rich");
+        assert_eq!(
+            serde_json::from_str::<Value>(&sink.snapshot()).unwrap(),
+            json!(sink.payloads())
+        );
+    }
+
+    #[test]
+    fn test_mock_sink_conversion_error() {
+        let mut sink = SerenityMockSink::new();
+
+        let sent = sink.send(&SerenityMessage::builder().content("a".repeat(2001)).build());
+        assert!(sent.is_err());
+        sink.assert_sent_count(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an embed with title")]
+    fn test_mock_sink_missing_embed_title() {
+        let mut sink = SerenityMockSink::new();
+
+        sink.send(&SerenityMessage::builder().content(MOCK_TEXT).build())
+            .unwrap();
+        sink.assert_sent_embed_title(MOCK_TEXT);
+    }
+}
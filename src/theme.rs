@@ -0,0 +1,276 @@
+use crate::model::color::SerenityColor;
+use crate::model::embed::{SerenityEmbed, SerenityEmbedField};
+use crate::model::theme::{SerenityEmbedFieldMerge, SerenityEmbedSeverity, SerenityEmbedTheme};
+
+impl SerenityEmbedSeverity {
+    /// The color used when the theme does not set one for this severity.
+    pub const fn default_color(self) -> SerenityColor {
+        match self {
+            Self::Info => SerenityColor::BLURPLE,
+            Self::Success => SerenityColor::GREEN,
+            Self::Warning => SerenityColor::YELLOW,
+            Self::Error => SerenityColor::RED,
+        }
+    }
+}
+
+impl SerenityEmbedTheme {
+    /// Apply the theme to an embed.
+    ///
+    /// ```rs
+    /// let embed = theme.apply(SerenityEmbed::builder().title("Hello").build());
+    /// ```
+    ///
+    /// # Merge order
+    ///
+    /// - Values set on the embed always win. Unset values are taken from [SerenityEmbedTheme::base].
+    /// - The footer (`footer_text`, `footer_icon_url`) and the author block (`author_name`, `author_url`, `author_icon_url`) are taken as a whole:
+    ///   if the embed sets any value of the group, none of the group is taken from the base, so authors and footers are never mixed.
+    /// - Fields are combined according to [SerenityEmbedTheme::field_merge].
+    pub fn apply(&self, embed: SerenityEmbed) -> SerenityEmbed {
+        let base = &self.base;
+
+        let (footer_text, footer_icon_url) =
+            if embed.footer_text.is_some() || embed.footer_icon_url.is_some() {
+                (embed.footer_text, embed.footer_icon_url)
+            } else {
+                (base.footer_text.clone(), base.footer_icon_url.clone())
+            };
+
+        let (author_name, author_url, author_icon_url) = if embed.author_name.is_some()
+            || embed.author_url.is_some()
+            || embed.author_icon_url.is_some()
+        {
+            (embed.author_name, embed.author_url, embed.author_icon_url)
+        } else {
+            (
+                base.author_name.clone(),
+                base.author_url.clone(),
+                base.author_icon_url.clone(),
+            )
+        };
+
+        SerenityEmbed {
+            title: embed.title.or_else(|| base.title.clone()),
+            description: embed.description.or_else(|| base.description.clone()),
+            url: embed.url.or_else(|| base.url.clone()),
+            timestamp: embed.timestamp.or(base.timestamp),
+            color: embed.color.or(base.color),
+            footer_text,
+            footer_icon_url,
+            image_url: embed.image_url.or_else(|| base.image_url.clone()),
+            thumbnail_url: embed.thumbnail_url.or_else(|| base.thumbnail_url.clone()),
+            author_name,
+            author_url,
+            author_icon_url,
+            fields: merge_fields(self.field_merge, base.fields.as_ref(), embed.fields),
+        }
+    }
+
+    /// Apply the theme to an embed with the color of a severity.
+    ///
+    /// A color set on the embed still wins. Otherwise the color of the severity replaces the color of [SerenityEmbedTheme::base].
+    pub fn apply_severity(
+        &self,
+        severity: SerenityEmbedSeverity,
+        embed: SerenityEmbed,
+    ) -> SerenityEmbed {
+        let color = embed.color.unwrap_or_else(|| self.color(severity));
+        SerenityEmbed {
+            color: Some(color),
+            ..self.apply(embed)
+        }
+    }
+
+    /// The color of a severity in this theme.
+    pub fn color(&self, severity: SerenityEmbedSeverity) -> SerenityColor {
+        let color = match severity {
+            SerenityEmbedSeverity::Info => self.info_color,
+            SerenityEmbedSeverity::Success => self.success_color,
+            SerenityEmbedSeverity::Warning => self.warning_color,
+            SerenityEmbedSeverity::Error => self.error_color,
+        };
+        color.unwrap_or_else(|| severity.default_color())
+    }
+
+    /// Apply the theme with the [SerenityEmbedSeverity::Info] preset.
+    pub fn info(&self, embed: SerenityEmbed) -> SerenityEmbed {
+        self.apply_severity(SerenityEmbedSeverity::Info, embed)
+    }
+
+    /// Apply the theme with the [SerenityEmbedSeverity::Success] preset.
+    pub fn success(&self, embed: SerenityEmbed) -> SerenityEmbed {
+        self.apply_severity(SerenityEmbedSeverity::Success, embed)
+    }
+
+    /// Apply the theme with the [SerenityEmbedSeverity::Warning] preset.
+    pub fn warning(&self, embed: SerenityEmbed) -> SerenityEmbed {
+        self.apply_severity(SerenityEmbedSeverity::Warning, embed)
+    }
+
+    /// Apply the theme with the [SerenityEmbedSeverity::Error] preset.
+    pub fn error(&self, embed: SerenityEmbed) -> SerenityEmbed {
+        self.apply_severity(SerenityEmbedSeverity::Error, embed)
+    }
+}
+
+fn merge_fields(
+    merge: SerenityEmbedFieldMerge,
+    base: Option<&Vec<SerenityEmbedField>>,
+    fields: Option<Vec<SerenityEmbedField>>,
+) -> Option<Vec<SerenityEmbedField>> {
+    let Some(base) = base else {
+        return fields;
+    };
+    match (merge, fields) {
+        (_, None) => Some(base.clone()),
+        (SerenityEmbedFieldMerge::Replace, Some(fields)) => Some(fields),
+        (SerenityEmbedFieldMerge::Prepend, Some(fields)) => {
+            Some(base.iter().cloned().chain(fields).collect())
+        }
+        (SerenityEmbedFieldMerge::Append, Some(mut fields)) => {
+            fields.extend(base.iter().cloned());
+            Some(fields)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{Colour, CreateEmbed};
+    use serenity::json::json;
+
+    use super::*;
+
+    static MOCK_TEXT: &str = "This is a test text.";
+    static MOCK_URL: &str = "https://example.com";
+
+    fn field(name: &str) -> SerenityEmbedField {
+        SerenityEmbedField::builder()
+            .name(name)
+            .value(MOCK_TEXT)
+            .build()
+    }
+
+    fn mock_theme(field_merge: SerenityEmbedFieldMerge) -> SerenityEmbedTheme {
+        SerenityEmbedTheme::builder()
+            .base(
+                SerenityEmbed::builder()
+                    .title(MOCK_TEXT)
+                    .color(SerenityColor::BLACK)
+                    .footer_text(MOCK_TEXT)
+                    .footer_icon_url(MOCK_URL)
+                    .author_name(MOCK_TEXT)
+                    .author_icon_url(MOCK_URL)
+                    .fields(vec![field("base")])
+                    .build(),
+            )
+            .field_merge(field_merge)
+            .build()
+    }
+
+    #[test]
+    fn test_theme_apply() {
+        let embed = SerenityEmbed::builder()
+            .title("Explicit")
+            .author_name("Someone else")
+            .fields(vec![field("embed")])
+            .build();
+
+        // serenity-builder
+        let themed = mock_theme(SerenityEmbedFieldMerge::Append).apply(embed);
+        // serenity
+        let serenity_embed = CreateEmbed::default()
+            .title("Explicit")
+            .color(Colour(SerenityColor::BLACK.value()))
+            .footer(serenity::builder::CreateEmbedFooter::new(MOCK_TEXT).icon_url(MOCK_URL))
+            .author(serenity::builder::CreateEmbedAuthor::new("Someone else"))
+            .fields(vec![
+                ("embed".to_string(), MOCK_TEXT.to_string(), false),
+                ("base".to_string(), MOCK_TEXT.to_string(), false),
+            ]);
+
+        assert_eq!(
+            serde_json::to_value(themed.convert().unwrap()).unwrap(),
+            serde_json::to_value(serenity_embed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_theme_field_merge() {
+        let names = |merge, fields: Option<Vec<SerenityEmbedField>>| {
+            let embed = SerenityEmbed {
+                fields,
+                ..Default::default()
+            };
+            mock_theme(merge)
+                .apply(embed)
+                .fields
+                .unwrap()
+                .into_iter()
+                .map(|field| field.name)
+                .collect::<Vec<_>>()
+        };
+        let fields = || Some(vec![field("embed")]);
+
+        assert_eq!(
+            names(SerenityEmbedFieldMerge::Prepend, fields()),
+            ["base", "embed"]
+        );
+        assert_eq!(
+            names(SerenityEmbedFieldMerge::Append, fields()),
+            ["embed", "base"]
+        );
+        assert_eq!(names(SerenityEmbedFieldMerge::Replace, fields()), ["embed"]);
+        assert!(names(SerenityEmbedFieldMerge::Replace, Some(vec![])).is_empty());
+        assert_eq!(names(SerenityEmbedFieldMerge::Replace, None), ["base"]);
+    }
+
+    #[test]
+    fn test_theme_presets() {
+        let theme = SerenityEmbedTheme::builder()
+            .warning_color(SerenityColor::FUCHSIA)
+            .build();
+
+        assert_eq!(
+            theme.info(SerenityEmbed::default()).color,
+            Some(SerenityColor::BLURPLE)
+        );
+        assert_eq!(
+            theme.warning(SerenityEmbed::default()).color,
+            Some(SerenityColor::FUCHSIA)
+        );
+        assert_eq!(
+            mock_theme(SerenityEmbedFieldMerge::Append)
+                .error(SerenityEmbed::default())
+                .color,
+            Some(SerenityColor::RED)
+        );
+        assert_eq!(
+            theme
+                .success(SerenityEmbed::builder().color(SerenityColor::WHITE).build())
+                .color,
+            Some(SerenityColor::WHITE)
+        );
+    }
+
+    #[test]
+    fn test_theme_deserialize() {
+        let theme: SerenityEmbedTheme = serde_json::from_value(json!({
+            "base": { "footer_text": MOCK_TEXT },
+            "field_merge": "prepend",
+            "error_color": "#000000"
+        }))
+        .unwrap();
+
+        assert_eq!(theme.field_merge, SerenityEmbedFieldMerge::Prepend);
+        assert_eq!(
+            theme.color(SerenityEmbedSeverity::Error),
+            SerenityColor::from(0)
+        );
+        assert_eq!(
+            theme.apply(SerenityEmbed::default()).footer_text.as_deref(),
+            Some(MOCK_TEXT)
+        );
+    }
+}
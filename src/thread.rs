@@ -0,0 +1,220 @@
+use serenity::all::CreateThread;
+
+use crate::convert::impl_convert;
+use crate::error::ErrorKind;
+use crate::model::thread::{SerenityThread, SerenityThreadType};
+
+/// Errors that can occur when converting a [SerenityThread] to a [serenity::all::CreateThread].
+#[derive(thiserror::Error, Debug)]
+pub enum SerenityThreadConvertError {
+    /**
+     * This occurs when the thread name is empty or exceeds 100 characters, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The name must be between {min} and {max} characters, but is {actual}.")]
+    InvalidNameLength {
+        actual: usize,
+        min: usize,
+        max: usize,
+    },
+    /**
+     * This occurs when the slowmode exceeds 21600 seconds, which is a limitation imposed by the Discord API.
+     *
+     * Serenity-builder will report an error during conversion.
+     * Serenity does not return an error and leaves the response entirely up to the Discord API.
+     */
+    #[error("The slowmode is too long: {actual} seconds (limit: {limit}).")]
+    TooLongSlowmode { actual: u16, limit: u16 },
+    /**
+     * This occurs when a private thread is requested together with a starter message.
+     * Threads started from a message are always public.
+     */
+    #[error("A thread started from a message cannot be private.")]
+    PrivateThreadFromMessage,
+}
+
+impl SerenityThreadConvertError {
+    /// The category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidNameLength { .. } => ErrorKind::Length,
+            Self::TooLongSlowmode { .. } => ErrorKind::Range,
+            Self::PrivateThreadFromMessage => ErrorKind::Conflict,
+        }
+    }
+
+    /// The path of the offending value within the thread.
+    pub fn field_path(&self) -> String {
+        match self {
+            Self::InvalidNameLength { .. } => "name",
+            Self::TooLongSlowmode { .. } => "slowmode",
+            Self::PrivateThreadFromMessage => "kind",
+        }
+        .to_string()
+    }
+}
+
+impl SerenityThread {
+    /// Convert the thread structure created in Builder into a model usable in Serenity.
+    ///
+    /// ```rs
+    /// let thread = SerenityThread::builder()
+    ///   .name("This is a test thread.")
+    ///   .build();
+    ///
+    /// let serenity_thread = thread.convert()?; // Result<CreateThread, SerenityThreadConvertError>
+    /// ```
+    ///
+    /// # How to use
+    ///
+    /// ```rs
+    /// // 1. Create a SerenityThread using the builder
+    /// let thread = SerenityThread::builder()
+    ///   .name("This is a test thread.")
+    ///   .auto_archive_duration(SerenityThreadAutoArchiveDuration::OneDay)
+    ///   .starter_message(message.id)
+    ///   .build(); // Don't forget!: If you forget this, you won't be able to use `convert()`.
+    ///
+    /// // 2. Convert to Serenity's CreateThread
+    /// let serenity_thread = thread.convert()?; // Result<CreateThread, SerenityThreadConvertError>
+    ///
+    /// // 3. Use the converted thread in your bot
+    /// let channel = match thread.starter_message {
+    ///     Some(message_id) => message.channel_id.create_thread_from_message(&ctx.http, message_id, serenity_thread).await?,
+    ///     None => message.channel_id.create_thread(&ctx.http, serenity_thread).await?,
+    /// };
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function may return the following error:
+    ///
+    /// - [SerenityThreadConvertError::InvalidNameLength]: The name is empty or exceeds 100 characters.
+    /// - [SerenityThreadConvertError::TooLongSlowmode]: The slowmode exceeds 21600 seconds.
+    /// - [SerenityThreadConvertError::PrivateThreadFromMessage]: A private thread was requested together with a starter message.
+    pub fn convert(&self) -> Result<CreateThread<'static>, SerenityThreadConvertError> {
+        let name_length = self.name.encode_utf16().count();
+        if !(1..=100).contains(&name_length) {
+            return Err(SerenityThreadConvertError::InvalidNameLength {
+                actual: name_length,
+                min: 1,
+                max: 100,
+            });
+        }
+
+        let mut thread = CreateThread::new(&self.name);
+
+        if let Some(kind) = &self.kind {
+            if matches!(kind, SerenityThreadType::Private) && self.starter_message.is_some() {
+                return Err(SerenityThreadConvertError::PrivateThreadFromMessage);
+            }
+            thread = thread.kind(kind.into());
+        }
+
+        if let Some(duration) = self.auto_archive_duration {
+            thread = thread.auto_archive_duration(duration.into());
+        }
+
+        if let Some(invitable) = self.invitable {
+            thread = thread.invitable(invitable);
+        }
+
+        if let Some(slowmode) = self.slowmode {
+            if slowmode > 21600 {
+                return Err(SerenityThreadConvertError::TooLongSlowmode {
+                    actual: slowmode,
+                    limit: 21600,
+                });
+            }
+            thread = thread.rate_limit_per_user(slowmode);
+        }
+
+        Ok(thread)
+    }
+}
+
+impl_convert!(SerenityThread => CreateThread<'static>, SerenityThreadConvertError);
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{AutoArchiveDuration, ChannelType, MessageId};
+
+    use super::*;
+    use crate::model::thread::SerenityThreadAutoArchiveDuration;
+
+    static MOCK_TEXT: &str = "This is a test thread.";
+    static MOCK_MESSAGE_ID: u64 = 123456789012345678;
+
+    #[test]
+    fn test_thread_conversion() {
+        // serenity-builder
+        let mock_thread = SerenityThread::builder()
+            .name(MOCK_TEXT)
+            .kind(SerenityThreadType::Private)
+            .auto_archive_duration(SerenityThreadAutoArchiveDuration::OneWeek)
+            .invitable(false)
+            .slowmode(60_u16)
+            .build();
+        // serenity
+        let serenity_thread = CreateThread::new(MOCK_TEXT)
+            .kind(ChannelType::PrivateThread)
+            .auto_archive_duration(AutoArchiveDuration::OneWeek)
+            .invitable(false)
+            .rate_limit_per_user(60);
+
+        let converted = mock_thread.convert();
+
+        assert!(converted.is_ok());
+        assert_eq!(
+            serde_json::to_value(converted.unwrap()).unwrap(),
+            serde_json::to_value(serenity_thread).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_thread_conversion_invalid_name_length() {
+        for name in [String::new(), "a".repeat(101)] {
+            let mock_thread = SerenityThread::builder().name(name).build();
+
+            let converted = mock_thread.convert();
+            assert!(matches!(
+                converted,
+                Err(SerenityThreadConvertError::InvalidNameLength { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_thread_conversion_too_long_slowmode() {
+        let mock_thread = SerenityThread::builder()
+            .name(MOCK_TEXT)
+            .slowmode(21601_u16)
+            .build();
+
+        let converted = mock_thread.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityThreadConvertError::TooLongSlowmode {
+                actual: 21601,
+                limit: 21600
+            })
+        ));
+    }
+
+    #[test]
+    fn test_thread_conversion_private_thread_from_message() {
+        let mock_thread = SerenityThread::builder()
+            .name(MOCK_TEXT)
+            .kind(SerenityThreadType::Private)
+            .starter_message(MessageId::new(MOCK_MESSAGE_ID))
+            .build();
+
+        let converted = mock_thread.convert();
+        assert!(matches!(
+            converted,
+            Err(SerenityThreadConvertError::PrivateThreadFromMessage)
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn test_command_derive_diagnostics() {
+    trybuild::TestCases::new().compile_fail("tests/ui/command/*.rs");
+}
+
+#[test]
+fn test_embed_derive_diagnostics() {
+    trybuild::TestCases::new().compile_fail("tests/ui/embed/*.rs");
+}
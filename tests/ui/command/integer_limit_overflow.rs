@@ -0,0 +1,10 @@
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+#[command(description = "Look up a message")]
+struct Lookup {
+    #[command(description = "The id", max = 9223372036854775808)]
+    id: i64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+#[command(name = "Ban", description = "Ban a member")]
+struct Ban {}
+
+fn main() {}
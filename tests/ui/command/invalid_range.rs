@@ -0,0 +1,10 @@
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+#[command(description = "Ban a member")]
+struct Ban {
+    #[command(description = "Days", min = 9007199254740993, max = 9007199254740992)]
+    days: i64,
+}
+
+fn main() {}
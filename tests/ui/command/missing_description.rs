@@ -0,0 +1,6 @@
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+struct Ping {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serenity::all::UserId;
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+#[command(description = "Ban a member")]
+struct Ban {
+    #[command(description = "The reason")]
+    reason: Option<String>,
+    #[command(description = "The member to ban")]
+    member: UserId,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serenity_builder::SerenityCommand;
+
+#[derive(SerenityCommand)]
+#[command(description = "Ban a member")]
+struct Ban {
+    #[command(description = "Days", step = 1)]
+    days: i64,
+}
+
+fn main() {}
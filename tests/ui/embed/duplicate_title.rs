@@ -0,0 +1,11 @@
+use serenity_builder::IntoSerenityEmbed;
+
+#[derive(IntoSerenityEmbed)]
+struct MatchResult {
+    #[embed(title)]
+    name: String,
+    #[embed(title)]
+    nickname: String,
+}
+
+fn main() {}
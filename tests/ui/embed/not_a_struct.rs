@@ -0,0 +1,9 @@
+use serenity_builder::IntoSerenityEmbed;
+
+#[derive(IntoSerenityEmbed)]
+enum MatchResult {
+    Won,
+    Lost,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serenity_builder::IntoSerenityEmbed;
+
+#[derive(IntoSerenityEmbed)]
+struct MatchResult {
+    #[embed(subtitle)]
+    name: String,
+}
+
+fn main() {}